@@ -0,0 +1,46 @@
+//! Declares the [`Finite`] trait.
+
+/// A type with finitely many values, all of which can be listed.
+///
+/// ```
+/// use algebra::Finite;
+///
+/// assert_eq!(i8::order(), 256);
+/// assert!(bool::elements().eq([false, true]));
+/// ```
+pub trait Finite: Sized {
+    /// Returns the number of values of the type.
+    fn order() -> usize;
+
+    /// Returns an iterator over every value of the type, each listed once.
+    fn elements() -> impl Iterator<Item = Self>;
+}
+
+impl Finite for bool {
+    fn order() -> usize {
+        2
+    }
+
+    fn elements() -> impl Iterator<Item = Self> {
+        IntoIterator::into_iter([false, true])
+    }
+}
+
+/// Implements [`Finite`] for small primitive integers.
+macro_rules! impl_finite {
+    ($($type:ty),*) => {
+        $(
+            impl Finite for $type {
+                fn order() -> usize {
+                    1 << <$type>::BITS
+                }
+
+                fn elements() -> impl Iterator<Item = Self> {
+                    <$type>::MIN..=<$type>::MAX
+                }
+            }
+        )*
+    };
+}
+
+impl_finite!(u8, u16, i8, i16);
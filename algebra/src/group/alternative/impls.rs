@@ -0,0 +1,70 @@
+//! Implements the appropriate alternativity traits for all primitives.
+
+use super::*;
+use crate::ops::{Add, Mul};
+
+/// Implements the traits implied by being a commutative semigroup under a
+/// given operation for a type.
+macro_rules! impl_alternative {
+    ($op:ty: $type:ty) => {
+        impl Flexible<$op> for $type {}
+        impl LeftAlternative<$op> for $type {}
+        impl RightAlternative<$op> for $type {}
+        impl Medial<$op> for $type {}
+    };
+}
+
+/// Implements the alternativity traits under addition and multiplication for
+/// a primitive.
+macro_rules! impl_arith_alt {
+    ($($type:ty),*) => {
+        $(
+            impl_alternative!(Add: $type);
+            impl_alternative!(Mul: $type);
+        )*
+    };
+}
+
+impl_arith_alt!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the alternativity laws under an operation for some values.
+    fn test_alt<Op: BinOpMarker, T: Alternative<Op> + Medial<Op> + PartialEq>(samples: &[T]) {
+        for a in samples {
+            for b in samples {
+                assert!(<T as Flexible<Op>>::test_flexible(a, b));
+                assert!(<T as LeftAlternative<Op>>::test_left_alt(a, b));
+                assert!(<T as RightAlternative<Op>>::test_right_alt(a, b));
+
+                for c in samples {
+                    for d in samples {
+                        assert!(<T as Medial<Op>>::test_medial(a, b, c, d));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Tests the alternativity laws under addition and multiplication for some
+    /// primitives, on a range of values.
+    macro_rules! test_arith_alt {
+        ($($($type:ty),*: $samples:expr);*) => {
+            $($(
+                let samples: Vec<$type> = $samples.collect();
+                test_alt::<Add, _>(&samples);
+                test_alt::<Mul, _>(&samples);
+            )*)*
+        };
+    }
+
+    #[test]
+    fn primitives() {
+        test_arith_alt!(
+            u8, u16, u32, u64, u128: (0..=3);
+            i8, i16, i32, i64, i128: (-3..=3)
+        );
+    }
+}
@@ -0,0 +1,97 @@
+mod impls;
+
+use super::Magma;
+use crate::ops::BinOpMarker;
+
+/// A [`Magma`] is [flexible](https://en.wikipedia.org/wiki/Flexible_algebra)
+/// whenever `a op (b op a) = (a op b) op a` for all `a, b`.
+///
+/// It is the implementor's responsability to ratify that flexibility holds in
+/// a given data structure.
+pub trait Flexible<Op: BinOpMarker>: Magma<Op> {
+    /// Tests whether `a op (b op a) = (a op b) op a` for some values.
+    fn test_flexible(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = b.op(a);
+        a.op_assign_rhs(&mut lhs);
+
+        let mut rhs = a.op(b);
+        rhs.op_assign_lhs(a);
+
+        lhs == rhs
+    }
+}
+
+/// A [`Magma`] is [left alternative](https://en.wikipedia.org/wiki/Alternativity)
+/// whenever `a op (a op b) = (a op a) op b` for all `a, b`.
+///
+/// It is the implementor's responsability to ratify that left alternativity
+/// holds in a given data structure.
+pub trait LeftAlternative<Op: BinOpMarker>: Magma<Op> {
+    /// Tests whether `a op (a op b) = (a op a) op b` for some values.
+    fn test_left_alt(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = a.op(b);
+        a.op_assign_rhs(&mut lhs);
+
+        let mut rhs = a.op(a);
+        rhs.op_assign_lhs(b);
+
+        lhs == rhs
+    }
+}
+
+/// A [`Magma`] is [right alternative](https://en.wikipedia.org/wiki/Alternativity)
+/// whenever `(b op a) op a = b op (a op a)` for all `a, b`.
+///
+/// It is the implementor's responsability to ratify that right alternativity
+/// holds in a given data structure.
+pub trait RightAlternative<Op: BinOpMarker>: Magma<Op> {
+    /// Tests whether `(b op a) op a = b op (a op a)` for some values.
+    fn test_right_alt(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = b.op(a);
+        lhs.op_assign_lhs(a);
+
+        let mut rhs = a.op(a);
+        b.op_assign_rhs(&mut rhs);
+
+        lhs == rhs
+    }
+}
+
+/// An [alternative](https://en.wikipedia.org/wiki/Alternative_algebra) magma
+/// is [`LeftAlternative`], [`RightAlternative`], and [`Flexible`]. Any two of
+/// these properties imply the third.
+pub trait Alternative<Op: BinOpMarker>:
+    LeftAlternative<Op> + RightAlternative<Op> + Flexible<Op>
+{
+}
+
+impl<Op: BinOpMarker, T: LeftAlternative<Op> + RightAlternative<Op> + Flexible<Op>> Alternative<Op>
+    for T
+{
+}
+
+/// A [`Magma`] is [medial](https://en.wikipedia.org/wiki/Medial_magma)
+/// whenever `(a op b) op (c op d) = (a op c) op (b op d)` for all `a, b, c, d`.
+/// Every commutative semigroup is medial.
+///
+/// It is the implementor's responsability to ratify that mediality holds in a
+/// given data structure.
+pub trait Medial<Op: BinOpMarker>: Magma<Op> {
+    /// Tests whether `(a op b) op (c op d) = (a op c) op (b op d)` for some
+    /// values.
+    fn test_medial(a: &Self, b: &Self, c: &Self, d: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        Self::op(&a.op(b), &c.op(d)) == Self::op(&a.op(c), &b.op(d))
+    }
+}
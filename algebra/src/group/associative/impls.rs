@@ -2,10 +2,11 @@
 
 use crate::ops::{Add, Mul};
 
-/// Implements [`PowAssociative`] for a type.
+/// Implements [`PowAssociative`] and [`Associative`] for a type.
 macro_rules! impl_associativity {
     ($op:ty: $type:ty) => {
         impl crate::PowAssociative<$op> for $type {}
+        impl crate::Associative<$op> for $type {}
     };
 }
 
@@ -1,4 +1,4 @@
-pub mod impls;
+mod impls;
 
 use super::Magma;
 use crate::{numbers::Naturals, BinOpMarker};
@@ -10,9 +10,33 @@ use crate::{numbers::Naturals, BinOpMarker};
 /// It is the implementor's responsability to ratify that power associativity
 /// holds in a given data structure.
 pub trait PowAssociative<Op: BinOpMarker>: Magma<Op> {
+    /// Tests whether the first few powers of a value are well-defined. That
+    /// is, whether `aⁱ op aʲ = aⁱ⁺ʲ` whenever `i + j ≤ 4`, where powers are
+    /// evaluated from left to right.
+    fn test_pow_assoc(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let a2 = self.op(self);
+        let a3 = a2.op(self);
+        let a4 = a3.op(self);
+
+        self.op(&a2) == a3 && self.op(&a3) == a4 && a2.op(&a2) == a4
+    }
+
     /// Evaluates `b op b op ... b`, applied `e` times, and assigns it to
     /// `self`. Uses exponentiation by squares. If `e` is zero, leaves `self`
     /// unchanged.
+    ///
+    /// ```
+    /// use algebra::{Mul, PowAssociative};
+    ///
+    /// for e in 1..=40u32 {
+    ///     let mut x = 3u64;
+    ///     PowAssociative::<Mul>::pow_p(&mut x, e as u128);
+    ///     assert_eq!(x, 3u64.pow(e));
+    /// }
+    /// ```
     fn pow_p<N: Naturals>(&mut self, mut e: N)
     where
         Self: Clone,
@@ -21,14 +45,24 @@ pub trait PowAssociative<Op: BinOpMarker>: Magma<Op> {
             return;
         }
 
+        // `x` runs through the repeated squares of the base, and `self`
+        // accumulates those corresponding to the binary digits of `e`,
+        // starting from the lowest one that's set.
         let mut x = self.clone();
+        while e.is_even() {
+            x.op_assign_lhs(&x.clone());
+            e.div_assign_prim(2u32);
+        }
 
-        while e.gt_prim(1u32) {
+        *self = x.clone();
+        e.div_assign_prim(2u32);
+
+        while e.gt_prim(0u32) {
+            x.op_assign_lhs(&x.clone());
             if e.is_odd() {
                 self.op_assign_lhs(&x);
             }
 
-            x.op_assign_lhs(&x.clone());
             e.div_assign_prim(2u32);
         }
     }
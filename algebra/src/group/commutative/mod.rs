@@ -1,4 +1,4 @@
-pub mod impls;
+mod impls;
 
 use super::Magma;
 use crate::ops::BinOpMarker;
@@ -0,0 +1,61 @@
+//! Implements the group traits for all primitives.
+
+use super::{
+    AutomorphicInverseProperty, Group, LeftBolLoop, LeftInverseProperty, RightBolLoop,
+    RightInverseProperty,
+};
+use crate::ops::{Add, Neg};
+
+/// Implements `Group<Add>` for signed primitive integers, along with the loop
+/// properties satisfied by every abelian group.
+macro_rules! impl_add_group {
+    ($($type:ty),*) => {
+        $(
+            impl Group<Add> for $type {
+                type Inv = Neg;
+            }
+
+            impl LeftBolLoop<Add> for $type {}
+            impl RightBolLoop<Add> for $type {}
+            impl LeftInverseProperty<Add> for $type {}
+            impl RightInverseProperty<Add> for $type {}
+            impl AutomorphicInverseProperty<Add> for $type {}
+        )*
+    };
+}
+
+impl_add_group!(i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the group and loop laws under addition for some signed
+    /// primitives, on a range of values.
+    macro_rules! test_add_group {
+        ($($type:ty),*) => {
+            $(
+                let samples: Vec<$type> = (-3..=3).collect();
+                for a in &samples {
+                    assert!(Group::<Add>::test_two_sided_inv(a));
+
+                    for b in &samples {
+                        assert!(LeftInverseProperty::<Add>::test_left_inv_prop(a, b));
+                        assert!(RightInverseProperty::<Add>::test_right_inv_prop(a, b));
+                        assert!(AutomorphicInverseProperty::<Add>::test_auto_inv(a, b));
+
+                        for c in &samples {
+                            assert!(LeftBolLoop::<Add>::test_left_bol_loop(a, b, c));
+                            assert!(RightBolLoop::<Add>::test_right_bol_loop(a, b, c));
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn primitives() {
+        test_add_group!(i8, i16, i32, i64, i128);
+    }
+}
@@ -0,0 +1,52 @@
+use super::Associative;
+use crate::ops::BinOpMarker;
+
+/// An [inverse semigroup](https://en.wikipedia.org/wiki/Inverse_semigroup) is
+/// an [`Associative`] magma in which every element `x` has a unique
+/// generalized inverse `x⁻¹`, satisfying `x op x⁻¹ op x = x` and
+/// `x⁻¹ op x op x⁻¹ = x⁻¹`.
+///
+/// Equivalently, every element has such an inverse and any two idempotents
+/// commute. It is the implementor's responsability to ratify that these
+/// conditions hold.
+pub trait InverseSemigroup<Op: BinOpMarker>: Associative<Op> {
+    /// Returns the unique generalized inverse of a value.
+    fn gen_inv(&self) -> Self;
+
+    /// Evaluates the generalized inverse of a value and assigns it to `self`.
+    fn gen_inv_assign(&mut self) {
+        *self = self.gen_inv();
+    }
+
+    /// Tests whether `x op x⁻¹ op x = x` for a given value.
+    fn test_gen_inv(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = self.op(&self.gen_inv());
+        lhs.op_assign_lhs(self);
+        &lhs == self
+    }
+
+    /// Tests whether `x⁻¹ op x op x⁻¹ = x⁻¹` for a given value.
+    fn test_gen_inv_inv(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let inv = self.gen_inv();
+        let mut lhs = inv.op(self);
+        lhs.op_assign_lhs(&inv);
+        lhs == inv
+    }
+
+    /// Tests whether the idempotents `a op a⁻¹` and `b op b⁻¹` commute for
+    /// some given values.
+    fn test_idempotents_commute(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let e = a.op(&a.gen_inv());
+        let f = b.op(&b.gen_inv());
+        e.op(&f) == f.op(&e)
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Quasigroup, Unital};
+use super::{Commutative, Quasigroup, Unital};
 use crate::ops::BinOpMarker;
 
 /// A [loop](https://en.wikipedia.org/wiki/Quasigroup#Loops) is both [`Unital`]
@@ -76,3 +76,84 @@ pub trait RightBolLoop<Op: BinOpMarker>: Loop<Op> {
 pub trait MoufangLoop<Op: BinOpMarker>: LeftBolLoop<Op> + RightBolLoop<Op> {}
 
 impl<Op: BinOpMarker, T: LeftBolLoop<Op> + RightBolLoop<Op>> MoufangLoop<Op> for T {}
+
+/// A loop with the [left inverse property](https://en.wikipedia.org/wiki/Quasigroup#Inverse_properties),
+/// meaning that `a⁻¹ op (a op b) = b` for all `a, b`, where `a⁻¹` is the left
+/// inverse of `a`. Every [`LeftBolLoop`] has this property.
+///
+/// It is up to the implementor to ratify that this condition holds.
+pub trait LeftInverseProperty<Op: BinOpMarker>: Loop<Op> {
+    /// Tests whether two values satisfy the left inverse property.
+    fn test_left_inv_prop(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = a.quasi_op(b);
+        a.left_inv().quasi_op_assign_rhs(&mut lhs);
+        &lhs == b
+    }
+}
+
+/// A loop with the [right inverse property](https://en.wikipedia.org/wiki/Quasigroup#Inverse_properties),
+/// meaning that `(b op a) op a⁻¹ = b` for all `a, b`, where `a⁻¹` is the right
+/// inverse of `a`. Every [`RightBolLoop`] has this property.
+///
+/// It is up to the implementor to ratify that this condition holds.
+pub trait RightInverseProperty<Op: BinOpMarker>: Loop<Op> {
+    /// Tests whether two values satisfy the right inverse property.
+    fn test_right_inv_prop(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = b.quasi_op(a);
+        lhs.quasi_op_assign_lhs(&a.right_inv());
+        &lhs == b
+    }
+}
+
+/// An [inverse property loop](https://en.wikipedia.org/wiki/Quasigroup#Inverse_properties)
+/// has both the [`LeftInverseProperty`] and the [`RightInverseProperty`]. In
+/// such a loop, the left and right inverses of any value coincide.
+pub trait InverseProperty<Op: BinOpMarker>:
+    LeftInverseProperty<Op> + RightInverseProperty<Op>
+{
+}
+
+impl<Op: BinOpMarker, T: LeftInverseProperty<Op> + RightInverseProperty<Op>> InverseProperty<Op>
+    for T
+{
+}
+
+/// A loop with the automorphic inverse property is an [`InverseProperty`] loop
+/// where `(a op b)⁻¹ = a⁻¹ op b⁻¹` for all `a, b`. That is, taking inverses is
+/// an automorphism. Commutative inverse property loops, like commutative
+/// Moufang loops, have this property.
+///
+/// It is up to the implementor to ratify that this condition holds.
+///
+/// ```
+/// use algebra::*;
+///
+/// // Every abelian group has the automorphic inverse property.
+/// for a in -3..=3 {
+///     for b in -3..=3 {
+///         assert!(AutomorphicInverseProperty::<Add>::test_auto_inv(&a, &b));
+///     }
+/// }
+/// ```
+pub trait AutomorphicInverseProperty<Op: BinOpMarker>: InverseProperty<Op> {
+    /// Tests whether `(a op b)⁻¹ = a⁻¹ op b⁻¹` for some values.
+    fn test_auto_inv(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        a.quasi_op(b).right_inv() == a.right_inv().quasi_op(&b.right_inv())
+    }
+}
+
+/// A [commutative Moufang loop](https://en.wikipedia.org/wiki/Moufang_loop#Commutative_Moufang_loops)
+/// is both a [`MoufangLoop`] and [`Commutative`]. The smallest one that isn't
+/// an abelian group has 81 elements.
+pub trait CommutativeMoufangLoop<Op: BinOpMarker>: MoufangLoop<Op> + Commutative<Op> {}
+
+impl<Op: BinOpMarker, T: MoufangLoop<Op> + Commutative<Op>> CommutativeMoufangLoop<Op> for T {}
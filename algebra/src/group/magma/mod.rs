@@ -1,4 +1,4 @@
-pub mod impls;
+mod impls;
 
 use crate::{BinOpMarker, BinOpSet, Bws};
 
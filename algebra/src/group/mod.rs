@@ -24,21 +24,28 @@
 //! ```
 
 mod absorbing;
+mod alternative;
 mod associative;
 mod commutative;
+mod impls;
+mod inverse_semigroup;
 mod r#loop;
 mod magma;
 mod monoid;
 mod quasigroup;
+mod rack;
 mod unital;
 
 pub use absorbing::*;
+pub use alternative::*;
 pub use associative::*;
 pub use commutative::*;
+pub use inverse_semigroup::*;
 pub use magma::*;
 pub use monoid::*;
 pub use quasigroup::*;
 pub use r#loop::*;
+pub use rack::*;
 pub use unital::*;
 
 use crate::{BinOpMarker, UnOpMarker};
@@ -49,7 +56,17 @@ pub trait Group<Op: BinOpMarker>: Associative<Op> + Loop<Op> {
     /// The inverse operator.
     type Inv: UnOpMarker;
 
+    /// Returns the inverse of a value. In a group, the left and right inverses
+    /// coincide.
     fn inv(&self) -> Self {
-        todo!()
+        self.left_inv()
+    }
+
+    /// Tests whether the left and right inverses of a value coincide.
+    fn test_two_sided_inv(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self.left_inv() == self.right_inv()
     }
 }
@@ -1,7 +1,7 @@
 use super::Magma;
 use crate::ops::BinOpMarker;
 
-pub mod impls;
+mod impls;
 
 /// A [left quasigroup](https://en.wikipedia.org/wiki/Quasigroup) is a [`Magma`]
 /// together with a left division operator.
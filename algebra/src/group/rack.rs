@@ -0,0 +1,94 @@
+use super::{LeftQuasigroup, Magma};
+use crate::ops::BinOpMarker;
+
+/// A [`Magma`] is left self-distributive whenever
+/// `a op (b op c) = (a op b) op (a op c)` for all `a, b, c`.
+///
+/// It is the implementor's responsability to ratify that this condition holds
+/// in a given data structure.
+pub trait LeftDistributive<Op: BinOpMarker>: Magma<Op> {
+    /// Tests whether `a op (b op c) = (a op b) op (a op c)` for some values.
+    fn test_self_distrib(a: &Self, b: &Self, c: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        let mut lhs = b.op(c);
+        a.op_assign_rhs(&mut lhs);
+
+        lhs == Self::op(&a.op(b), &a.op(c))
+    }
+}
+
+/// A [rack](https://en.wikipedia.org/wiki/Racks_and_quandles) is a
+/// [`LeftDistributive`] [`LeftQuasigroup`]. That is, left multiplication by any
+/// value is an automorphism.
+pub trait Rack<Op: BinOpMarker>: LeftDistributive<Op> + LeftQuasigroup<Op> {}
+
+impl<Op: BinOpMarker, T: LeftDistributive<Op> + LeftQuasigroup<Op>> Rack<Op> for T {}
+
+/// A [quandle](https://en.wikipedia.org/wiki/Racks_and_quandles) is a [`Rack`]
+/// where `a op a = a` for all `a`. Conjugation `a op b = a b a⁻¹` in any group
+/// is the prototypical example.
+///
+/// It is the implementor's responsability to ratify that idempotency holds in
+/// a given data structure.
+///
+/// ```
+/// use algebra::*;
+///
+/// /// The dihedral quandle of order 5, where `a op b = 2a - b`.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Dihedral(u8);
+///
+/// impl BinOp<Mul> for Dihedral {
+///     type Output = Self;
+///     type Err = ();
+///
+///     fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+///         Ok(Self((2 * self.0 + 5 - rhs.0) % 5))
+///     }
+/// }
+///
+/// impl BinOpAssign<Mul> for Dihedral {
+///     type Err = ();
+///
+///     fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+///         *self = BinOp::<Mul>::bin_op(self, rhs)?;
+///         Ok(())
+///     }
+///
+///     fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+///         *rhs = BinOp::<Mul>::bin_op(self, rhs)?;
+///         Ok(())
+///     }
+/// }
+///
+/// impl Magma<Mul> for Dihedral {}
+/// impl LeftDistributive<Mul> for Dihedral {}
+/// impl Quandle<Mul> for Dihedral {}
+///
+/// // Left multiplication is an involution, so it's its own left division.
+/// impl LeftQuasigroup<Mul> for Dihedral {
+///     type LInv = Mul;
+/// }
+///
+/// let elements: Vec<_> = (0..5).map(Dihedral).collect();
+/// for a in &elements {
+///     assert!(a.test_idempotent());
+///     for b in &elements {
+///         assert!(a.test_left_div(b));
+///         for c in &elements {
+///             assert!(Dihedral::test_self_distrib(a, b, c));
+///         }
+///     }
+/// }
+/// ```
+pub trait Quandle<Op: BinOpMarker>: Rack<Op> {
+    /// Tests whether `a op a = a` for a given value.
+    fn test_idempotent(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        &<Self as Magma<Op>>::op(self, self) == self
+    }
+}
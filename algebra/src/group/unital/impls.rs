@@ -1 +1,67 @@
+//! Implements the appropriate unital traits for all primitives.
 
+use super::{LeftUnital, RightUnital};
+use crate::ops::{Add, Mul};
+
+/// Implements [`LeftUnital`] and [`RightUnital`] for a type, operation and
+/// identity.
+macro_rules! impl_unital {
+    ($op:ty, $id:literal: $type:ty) => {
+        impl LeftUnital<$op> for $type {
+            fn left_id() -> Self {
+                $id
+            }
+        }
+
+        impl RightUnital<$op> for $type {
+            fn right_id() -> Self {
+                $id
+            }
+        }
+    };
+}
+
+/// Implements the unital traits under addition and multiplication for a
+/// primitive.
+macro_rules! impl_arith_unital {
+    ($($type:ty),*) => {
+        $(
+            impl_unital!(Add, 0: $type);
+            impl_unital!(Mul, 1: $type);
+        )*
+    };
+}
+
+impl_arith_unital!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use super::super::Unital;
+    use super::*;
+
+    /// Tests the identities under addition and multiplication for some
+    /// primitives, on a range of values.
+    macro_rules! test_arith_unital {
+        ($($($type:ty),*: $samples:expr);*) => {
+            $($(
+                assert!(<$type as Unital<Add>>::test_id());
+                assert!(<$type as Unital<Mul>>::test_id());
+                let samples: Vec<$type> = $samples.collect();
+                for a in &samples {
+                    assert!(LeftUnital::<Add>::test_left_id(a));
+                    assert!(RightUnital::<Add>::test_right_id(a));
+                    assert!(LeftUnital::<Mul>::test_left_id(a));
+                    assert!(RightUnital::<Mul>::test_right_id(a));
+                }
+            )*)*
+        };
+    }
+
+    #[test]
+    fn primitives() {
+        test_arith_unital!(
+            u8, u16, u32, u64, u128: (0..=3);
+            i8, i16, i32, i64, i128: (-3..=3)
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! Contains the traits for left-unital, right-unital, and unital magmas.
 
-pub mod impls;
+mod impls;
 
 use super::Magma;
 use crate::ops::BinOpMarker;
@@ -27,7 +27,7 @@ pub trait RightUnital<Op: BinOpMarker>: Magma<Op> {
     fn right_id() -> Self;
 
     /// Tests whether the given value times the right identity equals the value.
-    fn test_left_id(&self) -> bool
+    fn test_right_id(&self) -> bool
     where
         Self: PartialEq,
     {
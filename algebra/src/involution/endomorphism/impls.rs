@@ -1 +1,23 @@
+//! Implements the endomorphism traits for all primitives.
 
+use super::Endomorphism;
+use crate::{Automorphism, Conj, Injective, Involution, Surjective};
+
+/// Implements conjugation on primitive integers as the trivial
+/// [`Involution`], so that they may serve as the base of hypercomplex types.
+macro_rules! impl_conj {
+    ($($type:ty),*) => {
+        $(
+            impl Endomorphism<Conj> for $type {}
+            impl Surjective<Conj> for $type {}
+            impl Automorphism<Conj> for $type {}
+            impl Involution<Conj> for $type {}
+
+            impl Injective<Conj> for $type {
+                type Inv = Conj;
+            }
+        )*
+    };
+}
+
+impl_conj!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
@@ -1,6 +1,6 @@
 use crate::{UnOpMarker, UnOpSet};
 
-pub mod impls;
+mod impls;
 
 /// Mathematically, an [endomorphism](https://en.wikipedia.org/wiki/Endomorphism)
 /// is a set together with a closed unary operation. No further restrictions are
@@ -1,14 +1,183 @@
 //! Defines algebraic structures.
 
+mod finite;
 mod group;
 mod involution;
 mod numbers;
 mod ops;
+mod product;
+mod ring;
 
+pub use finite::*;
 pub use group::*;
 pub use involution::*;
 pub use numbers::*;
 pub use ops::*;
+pub use ring::*;
+
+/// Derives the componentwise structure of a struct under some operations.
+/// Note that the division operators of quasigroups must be listed as well.
+///
+/// ```
+/// use algebra::{Add, Associative, Group, LeftUnital, Magma, Mul, Sub};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Magma)]
+/// #[magma(Add, Sub, Mul)]
+/// struct Pair {
+///     a: i64,
+///     b: i32,
+/// }
+///
+/// let x = Pair { a: 2, b: -3 };
+/// let y = Pair { a: 5, b: 7 };
+/// assert_eq!(Magma::<Mul>::op(&x, &y), Pair { a: 10, b: -21 });
+/// assert_eq!(Group::<Add>::inv(&x), Pair { a: -2, b: 3 });
+/// assert_eq!(<Pair as LeftUnital<Mul>>::left_id(), Pair { a: 1, b: 1 });
+/// assert!(Associative::<Add>::test_assoc(&x, &y, &x));
+/// ```
+///
+/// Tuples get the same structure out of the box.
+///
+/// ```
+/// use algebra::{Add, Group, Magma};
+///
+/// assert_eq!(Magma::<Add>::op(&(1i8, 2i64), &(3, 4)), (4, 6));
+/// assert_eq!(Group::<Add>::inv(&(1i8, 2i64)), (-1, -2));
+/// ```
+///
+/// Products of quandles are quandles.
+///
+/// ```
+/// use algebra::{bin_op, LeftDistributive, LeftQuasigroup, Magma, Mul, Quandle};
+///
+/// // The dihedral quandle of order 5.
+/// #[bin_op(Mul)]
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Dihedral(u8);
+///
+/// impl std::ops::Mul for Dihedral {
+///     type Output = Self;
+///
+///     fn mul(self, rhs: Self) -> Self {
+///         Self((2 * self.0 + 5 - rhs.0) % 5)
+///     }
+/// }
+///
+/// impl Magma<Mul> for Dihedral {}
+/// impl LeftDistributive<Mul> for Dihedral {}
+/// impl Quandle<Mul> for Dihedral {}
+///
+/// impl LeftQuasigroup<Mul> for Dihedral {
+///     type LInv = Mul;
+/// }
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, Magma)]
+/// #[magma(Mul)]
+/// struct Pair {
+///     a: Dihedral,
+///     b: Dihedral,
+/// }
+///
+/// let x = Pair { a: Dihedral(1), b: Dihedral(3) };
+/// let y = Pair { a: Dihedral(4), b: Dihedral(0) };
+/// assert!(Quandle::<Mul>::test_idempotent(&x));
+/// assert!(LeftQuasigroup::<Mul>::test_left_div(&x, &y));
+/// ```
+pub use macros::Magma;
+
+/// Implements `BinOp` and `BinOpAssign` in terms of the `std::ops` traits, or
+/// the `checked_*` methods when the `checked` flag is passed.
+///
+/// ```
+/// use algebra::{bin_op, un_op, BinOp, Magma, Neg, UnOp};
+///
+/// #[bin_op(Add, Mul, checked)]
+/// #[un_op(Neg, checked)]
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Small(i8);
+///
+/// impl Small {
+///     fn checked_add(self, rhs: Self) -> Option<Self> {
+///         self.0.checked_add(rhs.0).map(Small)
+///     }
+///
+///     fn checked_mul(self, rhs: Self) -> Option<Self> {
+///         self.0.checked_mul(rhs.0).map(Small)
+///     }
+///
+///     fn checked_neg(self) -> Option<Self> {
+///         self.0.checked_neg().map(Small)
+///     }
+/// }
+///
+/// assert_eq!(BinOp::<algebra::Add>::bin_op(&Small(100), &Small(27)), Ok(Small(127)));
+/// assert_eq!(BinOp::<algebra::Add>::bin_op(&Small(100), &Small(28)), Err(()));
+/// assert_eq!(UnOp::<Neg>::un_op(&Small(-128)), Err(()));
+/// ```
+///
+/// Types with `std::ops` implementations on references, like `&a + &b`, may
+/// use the `by_ref` flag to avoid cloning their arguments.
+///
+/// ```
+/// use algebra::{bin_op, BinOp, Mul};
+/// use std::ops;
+///
+/// #[bin_op(Mul, by_ref)]
+/// #[derive(Debug, PartialEq)]
+/// struct Big(Vec<u8>);
+///
+/// impl ops::Mul for &Big {
+///     type Output = Big;
+///
+///     fn mul(self, rhs: Self) -> Big {
+///         Big(self.0.iter().zip(&rhs.0).map(|(x, y)| x * y).collect())
+///     }
+/// }
+///
+/// assert_eq!(BinOp::<Mul>::bin_op(&Big(vec![2, 3]), &Big(vec![4, 5])), Ok(Big(vec![8, 15])));
+/// ```
+pub use macros::bin_op;
+
+/// Implements `UnOp` and `UnOpAssign` in terms of the `std::ops` traits, or
+/// the `checked_*` methods when the `checked` flag is passed. See [`bin_op`].
+pub use macros::un_op;
+
+/// Declares the algebraic structures a type satisfies, implementing the marker
+/// traits among them, and tests their laws on some samples.
+///
+/// Structures with associated items, such as the identities of
+/// [`LeftUnital`] or the inverse of a [`Group`], aren't implemented by the
+/// attribute, though their laws are still tested.
+///
+/// ```
+/// use algebra::{algebraic_structure, bin_op, LeftUnital, RightUnital};
+///
+/// #[algebraic_structure(CommutativeMonoid<Mul>; samples = (0..6).map(Bits))]
+/// #[bin_op(Mul)]
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Bits(u8);
+///
+/// impl std::ops::Mul for Bits {
+///     type Output = Self;
+///
+///     fn mul(self, rhs: Self) -> Self {
+///         Bits(self.0 & rhs.0)
+///     }
+/// }
+///
+/// impl LeftUnital<algebra::Mul> for Bits {
+///     fn left_id() -> Self {
+///         Bits(u8::MAX)
+///     }
+/// }
+///
+/// impl RightUnital<algebra::Mul> for Bits {
+///     fn right_id() -> Self {
+///         Bits(u8::MAX)
+///     }
+/// }
+/// ```
+pub use macros::algebraic_structure;
 
 #[cold]
 #[inline(always)]
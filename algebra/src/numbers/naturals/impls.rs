@@ -0,0 +1,37 @@
+//! Implements [`Naturals`] for the primitives that support it.
+
+use std::cmp;
+
+use super::{Naturals, PrimitiveNaturals};
+
+impl PrimitiveNaturals for u128 {}
+
+/// We assume **no overflow** occurs, as with the rest of the primitive
+/// implementations.
+impl Naturals for u128 {
+    type Word = u128;
+
+    fn is_zero(&self) -> bool {
+        *self == 0
+    }
+
+    fn cmp_prim<N: Into<u128>>(&self, n: N) -> cmp::Ordering {
+        self.cmp(&n.into())
+    }
+
+    fn is_even(&self) -> bool {
+        self.is_multiple_of(2)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        self - rhs
+    }
+
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self *= rhs;
+    }
+
+    fn div_assign_prim<N: Into<u128>>(&mut self, n: N) {
+        *self /= n.into();
+    }
+}
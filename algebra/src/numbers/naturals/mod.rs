@@ -1,3 +1,5 @@
+mod impls;
+
 use std::cmp;
 
 use crate::{Add, BinOp, CommutativeMonoid, Magma, Mul, Unital};
@@ -1,6 +1,6 @@
 //! Traits and types that represent operations.
 
-pub mod impls;
+mod impls;
 
 use core::fmt::Debug;
 use std::marker::PhantomData;
@@ -28,6 +28,10 @@ decl_bin_op_marker!(Add, "A type marker for addition.");
 decl_bin_op_marker!(Sub, "A type marker for subtraction.");
 decl_bin_op_marker!(Mul, "A type marker for multiplication.");
 decl_bin_op_marker!(Div, "A type marker for division.");
+decl_bin_op_marker!(
+    LDiv,
+    "A type marker for left division, so that `a LDiv b` solves `a · x = b`."
+);
 decl_bin_op_marker!(Rem, "A type marker for remainders.");
 decl_bin_op_marker!(BitAnd, "A type marker for bitwise and.");
 decl_bin_op_marker!(BitOr, "A type marker for bitwise or.");
@@ -137,14 +141,14 @@ impl<Op: BinOpMarker, Lhs, Rhs: BinOp<Op, Lhs>> BinOp<Bws<Op>, Rhs> for Lhs {
     }
 }
 
-impl<Op: BinOpMarker, Lhs, Rhs: BinOp<Op, Lhs>> BinOpAssign<Bws<Op>, Rhs> for Lhs {
+impl<Op: BinOpMarker, Lhs, Rhs: BinOpAssign<Op, Lhs>> BinOpAssign<Bws<Op>, Rhs> for Lhs {
     type Err = Rhs::Err;
 
     fn bin_op_assign_lhs(&mut self, rhs: &Rhs) -> Result<(), Self::Err> {
-        rhs.bin_op_assign_rhs(self)
+        <Rhs as BinOpAssign<Op, Lhs>>::bin_op_assign_rhs(rhs, self)
     }
 
     fn bin_op_assign_rhs(&self, rhs: &mut Rhs) -> Result<(), Self::Err> {
-        rhs.bin_op_assign_lhs(self)
+        <Rhs as BinOpAssign<Op, Lhs>>::bin_op_assign_lhs(rhs, self)
     }
 }
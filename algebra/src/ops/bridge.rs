@@ -0,0 +1,115 @@
+//! Implements the `std::ops` traits for algebraic structures.
+
+use std::ops;
+
+use crate::{
+    Add, BinOp, BinOpAssign, BitAnd, BitOr, BitXor, Div, Magma, Mul, Neg, Rem, Shl, Shr, Sub, UnOp,
+};
+
+/// A wrapper that implements the `std::ops` traits in terms of the algebraic
+/// structure of the inner type, so that any [`Magma`] may be operated on using
+/// the usual symbols.
+///
+/// ```
+/// use algebra::Std;
+///
+/// assert_eq!(Std(2i32) + Std(3), Std(5));
+/// assert_eq!(-Std(5i64), Std(-5));
+/// ```
+///
+/// Since the operators are safe, operations that fail, such as overflowing
+/// ones, panic instead, as the `std` operators do in debug mode.
+///
+/// ```should_panic
+/// use algebra::Std;
+///
+/// let _ = Std(i32::MAX) + Std(1);
+/// ```
+///
+/// ```should_panic
+/// use algebra::Std;
+///
+/// let mut x = Std(u8::MAX);
+/// x += Std(1);
+/// ```
+///
+/// ```should_panic
+/// use algebra::Std;
+///
+/// let _ = -Std(i64::MIN);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Std<T>(pub T);
+
+/// Panics after an operation fails.
+#[cold]
+fn failed(method: &str) -> ! {
+    panic!("attempt to {} failed", method)
+}
+
+/// Implements a `std::ops` trait for a magma under the corresponding operation.
+macro_rules! impl_std_op {
+    ($($op:ident, $tr:ident, $method:ident, $tr_assign:ident, $method_assign:ident);*) => {
+        $(
+            impl<T: Magma<$op>> ops::$tr for Std<T> {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    ops::$tr::$method(&self, &rhs)
+                }
+            }
+
+            impl<'a, T: Magma<$op>> ops::$tr<&'a Std<T>> for &'a Std<T> {
+                type Output = Std<T>;
+
+                fn $method(self, rhs: Self) -> Std<T> {
+                    match BinOp::<$op>::bin_op(&self.0, &rhs.0) {
+                        Ok(res) => Std(res),
+                        Err(_) => failed(stringify!($method)),
+                    }
+                }
+            }
+
+            impl<T: Magma<$op>> ops::$tr_assign for Std<T> {
+                fn $method_assign(&mut self, rhs: Self) {
+                    if BinOpAssign::<$op>::bin_op_assign_lhs(&mut self.0, &rhs.0).is_err() {
+                        failed(stringify!($method));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_std_op!(
+    Add, Add, add, AddAssign, add_assign;
+    Sub, Sub, sub, SubAssign, sub_assign;
+    Mul, Mul, mul, MulAssign, mul_assign;
+    Div, Div, div, DivAssign, div_assign;
+    Rem, Rem, rem, RemAssign, rem_assign;
+    BitAnd, BitAnd, bitand, BitAndAssign, bitand_assign;
+    BitOr, BitOr, bitor, BitOrAssign, bitor_assign;
+    BitXor, BitXor, bitxor, BitXorAssign, bitxor_assign;
+    Shl, Shl, shl, ShlAssign, shl_assign;
+    Shr, Shr, shr, ShrAssign, shr_assign
+);
+
+impl<T: UnOp<Neg, Output = T>> ops::Neg for Std<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<T: UnOp<Neg, Output = T>> ops::Neg for &Std<T> {
+    type Output = Std<T>;
+
+    fn neg(self) -> Std<T> {
+        match self.0.un_op() {
+            Ok(res) => Std(res),
+            Err(_) => failed("neg"),
+        }
+    }
+}
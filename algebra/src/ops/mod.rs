@@ -1,7 +1,9 @@
 pub mod binary;
+mod bridge;
 pub mod unary;
 
 pub use binary::*;
+pub use bridge::*;
 pub use unary::*;
 
 /// A trait representing type markers for n-ary operations.
@@ -20,12 +20,16 @@ macro_rules! impl_un_op {
 }
 
 impl_un_op!(
+    Neg, |x| x.checked_neg().ok_or(()),
+        {i8, i16, i32, i64, i128};
     Neg, |x| Ok(-x),
-        {i8, i16, i32, i64, i128, f32, f64};
+        {f32, f64};
     Rec, |x| (x != 0 as Self).then(|| (1 as Self) / x).ok_or(()),
         {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64};
     Not, |x| Ok(!x),
-        {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool}
+        {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool};
+    Conj, |x| Ok(x),
+        {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128}
 );
 
 /// Implements `UnOpAssign<op>` for a given operation.
@@ -45,10 +49,14 @@ macro_rules! impl_un_op_assign {
 }
 
 impl_un_op_assign!(
+    Neg, |x| {*x = x.checked_neg().ok_or(())?; Ok(())},
+        {i8, i16, i32, i64, i128};
     Neg, |x| {*x = -*x; Ok(())},
-        {i8, i16, i32, i64, i128, f32, f64};
+        {f32, f64};
     Rec, |x| (*x != 0 as Self).then(|| *x = (1 as Self) / *x).ok_or(()),
         {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64};
     Not, |x| {*x = !*x; Ok(())},
-        {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool}
+        {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool};
+    Conj, |_x| Ok(()),
+        {u8, u16, u32, u64, u128, i8, i16, i32, i64, i128}
 );
@@ -24,6 +24,7 @@ macro_rules! decl_un_op_marker {
 decl_un_op_marker!(Neg, "A type marker for negation.");
 decl_un_op_marker!(Rec, "A type marker for reciprocals.");
 decl_un_op_marker!(Not, "A type marker for logical negation.");
+decl_un_op_marker!(Conj, "A type marker for conjugation.");
 
 /// A trait that specifies that a given unary operation may be applied to a
 /// type. This is where such unary operation is actually defined.
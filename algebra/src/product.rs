@@ -0,0 +1,180 @@
+//! Implements the [direct product](https://en.wikipedia.org/wiki/Direct_product)
+//! of algebraic structures on tuples, where every operation is evaluated
+//! componentwise.
+//!
+//! Structs can get the same treatment via `#[derive(Magma)]`.
+
+use crate::{
+    Add, Associative, BinOp, BinOpAssign, BitAnd, BitOr, BitXor, Commutative, Div, Flexible, Group,
+    LeftAlternative, LeftDistributive, LeftQuasigroup, LeftUnital, Magma, Medial, Mul,
+    PowAssociative, Quandle, Rem, RightAlternative, RightQuasigroup, RightUnital, Shl, Shr, Sub,
+};
+
+/// Implements the componentwise structure of a tuple under a given operation.
+macro_rules! impl_product {
+    ($op:ident; $first:ident $fidx:tt $(, $name:ident $idx:tt)*) => {
+        impl<$first: Magma<$op>, $($name: Magma<$op>),*> BinOp<$op> for ($first, $($name,)*) {
+            type Output = Self;
+            type Err = ();
+
+            fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+                Ok((
+                    BinOp::<$op>::bin_op(&self.$fidx, &rhs.$fidx).map_err(drop)?,
+                    $(BinOp::<$op>::bin_op(&self.$idx, &rhs.$idx).map_err(drop)?,)*
+                ))
+            }
+        }
+
+        /// If an error occurs, the components before it will have already been
+        /// assigned.
+        impl<$first: Magma<$op>, $($name: Magma<$op>),*> BinOpAssign<$op>
+            for ($first, $($name,)*)
+        {
+            type Err = ();
+
+            fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+                BinOpAssign::<$op>::bin_op_assign_lhs(&mut self.$fidx, &rhs.$fidx)
+                    .map_err(drop)?;
+                $(
+                    BinOpAssign::<$op>::bin_op_assign_lhs(&mut self.$idx, &rhs.$idx)
+                        .map_err(drop)?;
+                )*
+                Ok(())
+            }
+
+            fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+                BinOpAssign::<$op>::bin_op_assign_rhs(&self.$fidx, &mut rhs.$fidx)
+                    .map_err(drop)?;
+                $(
+                    BinOpAssign::<$op>::bin_op_assign_rhs(&self.$idx, &mut rhs.$idx)
+                        .map_err(drop)?;
+                )*
+                Ok(())
+            }
+        }
+
+        impl<$first: Magma<$op>, $($name: Magma<$op>),*> Magma<$op> for ($first, $($name,)*) {}
+
+        impl<$first: PowAssociative<$op>, $($name: PowAssociative<$op>),*> PowAssociative<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: Associative<$op>, $($name: Associative<$op>),*> Associative<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: Commutative<$op>, $($name: Commutative<$op>),*> Commutative<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: Flexible<$op>, $($name: Flexible<$op>),*> Flexible<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: LeftAlternative<$op>, $($name: LeftAlternative<$op>),*> LeftAlternative<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: RightAlternative<$op>, $($name: RightAlternative<$op>),*> RightAlternative<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: Medial<$op>, $($name: Medial<$op>),*> Medial<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: LeftDistributive<$op>, $($name: LeftDistributive<$op>),*> LeftDistributive<$op>
+            for ($first, $($name,)*)
+        {
+        }
+
+        impl<$first: LeftUnital<$op>, $($name: LeftUnital<$op>),*> LeftUnital<$op>
+            for ($first, $($name,)*)
+        {
+            fn left_id() -> Self {
+                ($first::left_id(), $($name::left_id(),)*)
+            }
+        }
+
+        impl<$first: RightUnital<$op>, $($name: RightUnital<$op>),*> RightUnital<$op>
+            for ($first, $($name,)*)
+        {
+            fn right_id() -> Self {
+                ($first::right_id(), $($name::right_id(),)*)
+            }
+        }
+
+        impl<$first: LeftQuasigroup<$op>, $($name: LeftQuasigroup<$op, LInv = $first::LInv>),*>
+            LeftQuasigroup<$op> for ($first, $($name,)*)
+        where
+            Self: Magma<$first::LInv>,
+        {
+            type LInv = $first::LInv;
+        }
+
+        impl<$first: RightQuasigroup<$op>, $($name: RightQuasigroup<$op, RInv = $first::RInv>),*>
+            RightQuasigroup<$op> for ($first, $($name,)*)
+        where
+            Self: Magma<$first::RInv>,
+        {
+            type RInv = $first::RInv;
+        }
+
+        impl<
+                $first: Quandle<$op>,
+                $($name: Quandle<$op> + LeftQuasigroup<$op, LInv = $first::LInv>),*
+            > Quandle<$op> for ($first, $($name,)*)
+        where
+            Self: Magma<$first::LInv>,
+        {
+        }
+
+        impl<
+                $first: Group<$op>,
+                $($name: Group<$op, Inv = <$first as Group<$op>>::Inv>
+                    + LeftQuasigroup<$op, LInv = $first::LInv>
+                    + RightQuasigroup<$op, RInv = $first::RInv>),*
+            > Group<$op> for ($first, $($name,)*)
+        where
+            Self: Magma<$first::LInv> + Magma<$first::RInv>,
+        {
+            type Inv = <$first as Group<$op>>::Inv;
+        }
+    };
+}
+
+/// Implements the componentwise structure of a tuple under every operation.
+macro_rules! impl_product_ops {
+    ($($tt:tt)*) => {
+        impl_product!(Add; $($tt)*);
+        impl_product!(Sub; $($tt)*);
+        impl_product!(Mul; $($tt)*);
+        impl_product!(Div; $($tt)*);
+        impl_product!(Rem; $($tt)*);
+        impl_product!(BitAnd; $($tt)*);
+        impl_product!(BitOr; $($tt)*);
+        impl_product!(BitXor; $($tt)*);
+        impl_product!(Shl; $($tt)*);
+        impl_product!(Shr; $($tt)*);
+    };
+}
+
+impl_product_ops!(A 0);
+impl_product_ops!(A 0, B 1);
+impl_product_ops!(A 0, B 1, C 2);
+impl_product_ops!(A 0, B 1, C 2, D 3);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_product_ops!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
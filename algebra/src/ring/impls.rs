@@ -0,0 +1,72 @@
+//! Implements the ring traits for all primitives.
+
+use super::{EuclideanDomain, Ring};
+
+/// Implements [`Ring`] for signed primitive integers.
+macro_rules! impl_ring {
+    ($($type:ty),*) => {
+        $(
+            impl Ring for $type {}
+        )*
+    };
+}
+
+impl_ring!(i8, i16, i32, i64, i128);
+
+/// Implements [`EuclideanDomain`] for signed primitive integers, with the
+/// absolute value as the Euclidean function.
+///
+/// Division wraps around like [`i64::wrapping_div`], so that `MIN / -1` is
+/// `MIN` rather than overflowing. The remainder is zero in that case, which
+/// keeps the Euclidean algorithm correct.
+macro_rules! impl_euclid {
+    ($($type:ty: $norm:ty),*) => {
+        $(
+            impl EuclideanDomain for $type {
+                type Norm = $norm;
+
+                fn euclid_norm(&self) -> $norm {
+                    self.unsigned_abs()
+                }
+
+                fn div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+                    (*rhs != 0).then(|| (self.wrapping_div(*rhs), self.wrapping_rem(*rhs)))
+                }
+            }
+        )*
+    };
+}
+
+impl_euclid!(i8: u8, i16: u16, i32: u32, i64: u64, i128: u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the ring and Euclidean domain laws for some signed primitives, on
+    /// a range of values.
+    macro_rules! test_euclid {
+        ($($type:ty),*) => {
+            $(
+                let samples: Vec<$type> = (-3..=3).collect();
+                for a in &samples {
+                    for b in &samples {
+                        assert!(EuclideanDomain::test_div_rem(a, b));
+
+                        for c in &samples {
+                            assert!(Ring::test_left_distrib(a, b, c));
+                            assert!(Ring::test_right_distrib(a, b, c));
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn primitives() {
+        test_euclid!(i8, i16, i32, i64, i128);
+        assert_eq!(i64::MIN.div_rem(&-1), Some((i64::MIN, 0)));
+        assert_eq!(i8::MIN.gcd(&-1), -1);
+    }
+}
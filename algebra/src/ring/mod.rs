@@ -0,0 +1,176 @@
+//! All of the traits relating to a [`Ring`].
+
+mod impls;
+
+use crate::{Add, Commutative, Group, Magma, Monoid, Mul, Rec, UnOp, UnOpSet, Unital};
+
+/// A [ring](https://en.wikipedia.org/wiki/Ring_(mathematics)) is a
+/// [`Commutative`] [`Group`] under addition and a [`Monoid`] under
+/// multiplication, such that multiplication distributes over addition. That
+/// is, `a(b + c) = ab + ac` and `(a + b)c = ac + bc` for all `a, b, c`.
+///
+/// It is the implementor's responsability to ratify that distributivity holds
+/// in a given data structure.
+pub trait Ring: Group<Add> + Commutative<Add> + Monoid<Mul> {
+    /// Returns the additive identity.
+    fn zero() -> Self {
+        <Self as Unital<Add>>::id()
+    }
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self {
+        <Self as Unital<Mul>>::id()
+    }
+
+    /// Returns whether `self` is the additive identity.
+    fn is_zero(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        self == &Self::zero()
+    }
+
+    /// Adds two values.
+    fn add(&self, rhs: &Self) -> Self {
+        <Self as Magma<Add>>::op(self, rhs)
+    }
+
+    /// Adds two values and assigns the result to the former.
+    fn add_assign(&mut self, rhs: &Self) {
+        <Self as Commutative<Add>>::op_assign(self, rhs)
+    }
+
+    /// Returns the additive inverse of a value.
+    fn neg(&self) -> Self {
+        <Self as Group<Add>>::inv(self)
+    }
+
+    /// Subtracts two values.
+    fn sub(&self, rhs: &Self) -> Self {
+        self.add(&rhs.neg())
+    }
+
+    /// Subtracts two values and assigns the result to the former.
+    fn sub_assign(&mut self, rhs: &Self) {
+        self.add_assign(&rhs.neg())
+    }
+
+    /// Multiplies two values.
+    fn mul(&self, rhs: &Self) -> Self {
+        <Self as Magma<Mul>>::op(self, rhs)
+    }
+
+    /// Multiplies two values and assigns the result to the former.
+    fn mul_assign(&mut self, rhs: &Self) {
+        <Self as Magma<Mul>>::op_assign_lhs(self, rhs)
+    }
+
+    /// Tests whether `a(b + c) = ab + ac` for a given set of entries.
+    fn test_left_distrib(a: &Self, b: &Self, c: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        a.mul(&b.add(c)) == a.mul(b).add(&a.mul(c))
+    }
+
+    /// Tests whether `(a + b)c = ac + bc` for a given set of entries.
+    fn test_right_distrib(a: &Self, b: &Self, c: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        a.add(b).mul(c) == a.mul(c).add(&b.mul(c))
+    }
+}
+
+/// A [division ring](https://en.wikipedia.org/wiki/Division_ring) is a
+/// [`Ring`] in which every nonzero element has a multiplicative inverse. Taking
+/// the reciprocal of zero must return an error.
+pub trait DivisionRing: Ring + UnOpSet<Rec> {
+    /// Returns the multiplicative inverse of a value, or `None` if it's zero.
+    fn rec(&self) -> Option<Self> {
+        <Self as UnOp<Rec>>::un_op(self).ok()
+    }
+
+    /// Returns `self` times the inverse of `rhs`, or `None` if `rhs` is zero.
+    fn div(&self, rhs: &Self) -> Option<Self> {
+        rhs.rec().map(|r| self.mul(&r))
+    }
+
+    /// Tests whether `a a⁻¹ = a⁻¹ a = 1` for a value, or whether its reciprocal
+    /// is undefined if it's zero.
+    fn test_rec(&self) -> bool
+    where
+        Self: PartialEq,
+    {
+        match self.rec() {
+            Some(r) => self.mul(&r) == Self::one() && r.mul(self) == Self::one(),
+            None => self.is_zero(),
+        }
+    }
+}
+
+/// A [field](https://en.wikipedia.org/wiki/Field_(mathematics)) is a
+/// [`DivisionRing`] whose multiplication is [`Commutative`].
+pub trait Field: DivisionRing + Commutative<Mul> {}
+
+impl<T: DivisionRing + Commutative<Mul>> Field for T {}
+
+/// A [Euclidean domain](https://en.wikipedia.org/wiki/Euclidean_domain) is a
+/// [`Ring`] with [`Commutative`] multiplication and no zero divisors, together
+/// with a Euclidean function. This is such that for any `a` and non-zero `b`,
+/// there's a division `a = qb + r` where either `r = 0` or `f(r) < f(b)`.
+///
+/// It is the implementor's responsability to ratify that there are no zero
+/// divisors.
+///
+/// ```
+/// use algebra::EuclideanDomain;
+///
+/// assert_eq!(12i64.gcd(&-18).unsigned_abs(), 6);
+/// assert_eq!((-7i64).div_rem(&2), Some((-3, -1)));
+/// assert!(EuclideanDomain::test_div_rem(&-7i64, &2));
+/// assert_eq!(5i64.div_rem(&0), None);
+/// ```
+pub trait EuclideanDomain: Ring + Commutative<Mul> {
+    /// The values of the Euclidean function.
+    type Norm: Ord;
+
+    /// Evaluates the Euclidean function on a value.
+    fn euclid_norm(&self) -> Self::Norm;
+
+    /// Returns a quotient `q` and remainder `r` such that `self = q rhs + r`,
+    /// where either `r = 0` or `r` has a smaller norm than `rhs`. Returns
+    /// `None` if `rhs` is zero.
+    fn div_rem(&self, rhs: &Self) -> Option<(Self, Self)>;
+
+    /// Returns a [greatest common divisor](https://en.wikipedia.org/wiki/Greatest_common_divisor)
+    /// of two values, using the Euclidean algorithm. This is only unique up to
+    /// multiplication by units.
+    fn gcd(&self, rhs: &Self) -> Self
+    where
+        Self: Clone,
+    {
+        let (mut a, mut b) = (self.clone(), rhs.clone());
+        while let Some((_, r)) = a.div_rem(&b) {
+            a = b;
+            b = r;
+        }
+
+        a
+    }
+
+    /// Tests whether `a = qb + r` for the quotient and remainder of two values,
+    /// with `r` either zero or smaller than `b`, or whether division is
+    /// undefined if `b` is zero.
+    fn test_div_rem(a: &Self, b: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        match a.div_rem(b) {
+            Some((q, r)) => {
+                q.mul(b).add(&r) == *a && (r.is_zero() || r.euclid_norm() < b.euclid_norm())
+            }
+            None => b.is_zero(),
+        }
+    }
+}
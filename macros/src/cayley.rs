@@ -0,0 +1,85 @@
+//! Implements the `cayley_table!` macro.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, LitInt, Result, Token,
+};
+
+/// The rows of a Cayley table.
+struct Table(Vec<(proc_macro2::Span, Vec<LitInt>)>);
+
+impl Parse for Table {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut rows = Vec::new();
+        while !input.is_empty() {
+            let content;
+            let bracket = bracketed!(content in input);
+            let row = Punctuated::<LitInt, Token![,]>::parse_terminated(&content)?;
+            rows.push((bracket.span, row.into_iter().collect()));
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self(rows))
+    }
+}
+
+/// Validates that the table is square and its entries are in range, and
+/// returns them.
+fn entries(table: Table) -> Result<Vec<Vec<usize>>> {
+    let n = table.0.len();
+    table
+        .0
+        .iter()
+        .map(|(span, row)| {
+            if row.len() != n {
+                return Err(Error::new(
+                    *span,
+                    format!("expected {} entries in this row, found {}", n, row.len()),
+                ));
+            }
+
+            row.iter()
+                .map(|lit| {
+                    let v = lit.base10_parse()?;
+                    if v < n {
+                        Ok(v)
+                    } else {
+                        Err(Error::new(
+                            lit.span(),
+                            format!("entry {} out of range for a table of order {}", v, n),
+                        ))
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Expands the `cayley_table!` macro.
+pub fn cayley_table(input: TokenStream) -> TokenStream {
+    let rows = match syn::parse(input).and_then(entries) {
+        Ok(rows) => rows,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let n = rows.len();
+    (quote! {
+        // Safety: the entries were validated when the macro was expanded.
+        unsafe {
+            ::math::cayley::CayleyMagmaS::<#n>::new_unchecked(
+                ::math::storage::ArrayStorage([#(
+                    ::math::storage::ArrayStorage([#(::math::storage::Entry(#rows)),*])
+                ),*]),
+                #n,
+            )
+        }
+    })
+    .into()
+}
@@ -1,5 +1,12 @@
 extern crate proc_macro;
 
+mod cayley;
+mod ops;
+mod perm;
+mod product;
+mod storage;
+mod structure;
+
 use proc_macro::TokenStream;
 use quote::quote;
 
@@ -26,3 +33,94 @@ macro_rules! derive_marker {
 }
 
 derive_marker!(OpMarker, UnOpMarker, BinOpMarker);
+
+/// Builds a statically-sized finite magma from its Cayley table, given as a
+/// list of rows, validating it at compile time.
+///
+/// The table must be square, and its entries must be less than its order.
+/// Since no functions are called, the result may be assigned to a constant.
+#[proc_macro]
+pub fn cayley_table(input: TokenStream) -> TokenStream {
+    cayley::cayley_table(input)
+}
+
+/// Builds a statically-sized permutation, validating it at compile time.
+///
+/// The permutation may be given in one-line notation, listing the image of
+/// every point, as in `perm![2, 0, 1]`. Alternatively, it may be given in
+/// cycle notation, optionally followed by its size, as in `perm!((0 1)(2 3); 5)`.
+/// If the size is omitted, it's taken to be one more than the largest entry.
+///
+/// Repeated or out of range entries will raise a compile error.
+#[proc_macro]
+pub fn perm(input: TokenStream) -> TokenStream {
+    perm::perm(input)
+}
+
+/// Derives the componentwise structure of a struct, as a direct product of its
+/// fields, under every operation listed in a `#[magma(...)]` attribute.
+///
+/// `BinOp`, `BinOpAssign` and `Magma` are implemented whenever every field is
+/// a magma. Each of `PowAssociative`, `Associative`, `Commutative`,
+/// `Flexible`, `LeftAlternative`, `RightAlternative`, `Medial`,
+/// `LeftDistributive`, `LeftUnital`, `RightUnital`, `LeftQuasigroup`,
+/// `RightQuasigroup`, `Quandle` and `Group` is implemented whenever every field
+/// satisfies it, with the quasigroup and group inverses requiring the same
+/// operators in every field. These operators must be listed in the attribute
+/// as well.
+#[proc_macro_derive(Magma, attributes(magma))]
+pub fn magma(input: TokenStream) -> TokenStream {
+    product::derive_magma(input)
+}
+
+/// Implements `BinOp` and `BinOpAssign` for a type, for every operation marker
+/// listed, in terms of the corresponding `std::ops` trait.
+///
+/// The `checked` flag calls the `checked_*` methods instead, for those
+/// operations that have them, with `None` becoming an error. The `by_ref` flag
+/// passes both arguments by reference, as in `&a + &b`, rather than cloning
+/// them.
+#[proc_macro_attribute]
+pub fn bin_op(attr: TokenStream, item: TokenStream) -> TokenStream {
+    ops::bin_op(attr, item)
+}
+
+/// Implements `UnOp` and `UnOpAssign` for a type, for every operation marker
+/// listed, in terms of the corresponding `std::ops` trait. The flags work as in
+/// the `bin_op` attribute.
+#[proc_macro_attribute]
+pub fn un_op(attr: TokenStream, item: TokenStream) -> TokenStream {
+    ops::un_op(attr, item)
+}
+
+/// Declares the algebraic structures a type satisfies, as in
+/// `#[algebraic_structure(Group<Add>, Commutative<Add>; samples = expr)]`.
+///
+/// Every structure implied by the ones listed is considered. Only those which
+/// are mere marker traits, like `Associative` or `Ring`, get implemented.
+/// Structures with associated items, namely `LeftUnital`, `RightUnital`,
+/// `LeftAbsorbing`, `RightAbsorbing`, `LeftQuasigroup`, `RightQuasigroup`,
+/// `Group`, `InverseSemigroup` and `EuclideanDomain`, must be implemented by
+/// hand, as the attribute has no way to know their identities or inverses.
+/// Those with blanket implementations, like `Monoid` or `Field`, follow from
+/// the rest.
+///
+/// A test module is then emitted, which checks every law of these structures
+/// on all tuples of values yielded by the samples expression, whether the
+/// attribute implemented them or not. This should evaluate to something that
+/// can be turned into an iterator over the type.
+#[proc_macro_attribute]
+pub fn algebraic_structure(attr: TokenStream, item: TokenStream) -> TokenStream {
+    structure::algebraic_structure(attr, item)
+}
+
+/// Derives `StackStorage` for a `#[repr(C)]` or `#[repr(transparent)]` struct,
+/// whose fields are all stack storages with the same inner type. The struct is
+/// then laid out as a single array, made out of the fields in order.
+///
+/// This also implements `Storage`, `StorageMut`, and the `Index`, `Borrow`,
+/// `FromIterator` and `IntoIterator` traits these require.
+#[proc_macro_derive(StackStorage)]
+pub fn stack_storage(input: TokenStream) -> TokenStream {
+    storage::derive_stack_storage(input)
+}
@@ -0,0 +1,201 @@
+//! Implements the `bin_op` and `un_op` attribute macros.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    DeriveInput, Error, Ident, Result, Token,
+};
+
+/// The arguments of an operation attribute: the markers of the operations to
+/// implement, followed by any flags.
+struct OpArgs {
+    /// The markers of the operations to implement.
+    ops: Vec<Ident>,
+
+    /// Whether to call the `checked_*` methods instead of the `std::ops`
+    /// traits, whenever these exist.
+    checked: bool,
+
+    /// Whether the `std::ops` traits or `checked_*` methods take their
+    /// arguments by reference.
+    by_ref: bool,
+}
+
+impl Parse for OpArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = Self {
+            ops: Vec::new(),
+            checked: false,
+            by_ref: false,
+        };
+
+        for ident in Punctuated::<Ident, Token![,]>::parse_terminated(input)? {
+            if ident == "checked" {
+                args.checked = true;
+            } else if ident == "by_ref" {
+                args.by_ref = true;
+            } else {
+                args.ops.push(ident);
+            }
+        }
+
+        if args.ops.is_empty() {
+            return Err(Error::new(Span::call_site(), "expected some operations"));
+        }
+
+        Ok(args)
+    }
+}
+
+/// Returns the `std::ops` trait, its method, and the `checked_*` method
+/// corresponding to an operation marker, if any.
+fn std_op(op: &Ident, binary: bool) -> Result<(Ident, Ident, Option<Ident>)> {
+    let (tr, method, checked) = match (op.to_string().as_str(), binary) {
+        ("Add", true) => ("Add", "add", Some("checked_add")),
+        ("Sub", true) => ("Sub", "sub", Some("checked_sub")),
+        ("Mul", true) => ("Mul", "mul", Some("checked_mul")),
+        ("Div", true) => ("Div", "div", Some("checked_div")),
+        ("Rem", true) => ("Rem", "rem", Some("checked_rem")),
+        ("BitAnd", true) => ("BitAnd", "bitand", None),
+        ("BitOr", true) => ("BitOr", "bitor", None),
+        ("BitXor", true) => ("BitXor", "bitxor", None),
+        ("Shl", true) => ("Shl", "shl", None),
+        ("Shr", true) => ("Shr", "shr", None),
+        ("Neg", false) => ("Neg", "neg", Some("checked_neg")),
+        ("Not", false) => ("Not", "not", None),
+        _ => {
+            return Err(Error::new_spanned(
+                op,
+                "this operation has no `std::ops` counterpart",
+            ))
+        }
+    };
+
+    let ident = |s: &str| Ident::new(s, op.span());
+    Ok((ident(tr), ident(method), checked.map(ident)))
+}
+
+/// Appends the implementations of either binary or unary operations to an
+/// item, as specified by the arguments of the attribute.
+fn expand(attr: TokenStream, item: TokenStream, binary: bool) -> Result<TokenStream2> {
+    let args: OpArgs = syn::parse(attr)?;
+    let ast: DeriveInput = syn::parse(item)?;
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let preds: Vec<_> = where_clause
+        .map(|w| w.predicates.iter().collect())
+        .unwrap_or_default();
+    let this = quote! { #name #ty_generics };
+
+    let mut tokens = quote! { #ast };
+    for op in &args.ops {
+        let (tr, method, checked) = std_op(op, binary)?;
+        let checked = checked.filter(|_| args.checked);
+
+        // The arguments to pass, and the bound on the `std::ops` trait.
+        let (lhs, rhs, bound) = if args.by_ref {
+            (
+                quote! { self },
+                quote! { rhs },
+                if binary {
+                    quote! { for<'__a> &'__a #this: ::std::ops::#tr<&'__a #this, Output = #this> }
+                } else {
+                    quote! { for<'__a> &'__a #this: ::std::ops::#tr<Output = #this> }
+                },
+            )
+        } else {
+            (
+                quote! { ::std::clone::Clone::clone(self) },
+                quote! { ::std::clone::Clone::clone(rhs) },
+                quote! { #this: ::std::clone::Clone + ::std::ops::#tr<Output = #this> },
+            )
+        };
+
+        let (res, bound) = match (&checked, binary) {
+            (Some(checked), true) => (quote! { #lhs.#checked(#rhs).ok_or(()) }, None),
+            (Some(checked), false) => (quote! { #lhs.#checked().ok_or(()) }, None),
+            (None, true) => (
+                quote! { ::std::result::Result::Ok(::std::ops::#tr::#method(#lhs, #rhs)) },
+                Some(bound),
+            ),
+            (None, false) => (
+                quote! { ::std::result::Result::Ok(::std::ops::#tr::#method(#lhs)) },
+                Some(bound),
+            ),
+        };
+
+        let where_clause = quote! { where #(#preds,)* #bound };
+        tokens.extend(if binary {
+            quote! {
+                impl #impl_generics ::algebra::BinOp<::algebra::#op> for #this #where_clause {
+                    type Output = Self;
+                    type Err = ();
+
+                    fn bin_op(&self, rhs: &Self) -> ::std::result::Result<Self, ()> {
+                        #res
+                    }
+                }
+
+                impl #impl_generics ::algebra::BinOpAssign<::algebra::#op> for #this
+                #where_clause
+                {
+                    type Err = ();
+
+                    fn bin_op_assign_lhs(&mut self, rhs: &Self) -> ::std::result::Result<(), ()> {
+                        *self = ::algebra::BinOp::<::algebra::#op>::bin_op(self, rhs)?;
+                        ::std::result::Result::Ok(())
+                    }
+
+                    fn bin_op_assign_rhs(&self, rhs: &mut Self) -> ::std::result::Result<(), ()> {
+                        *rhs = ::algebra::BinOp::<::algebra::#op>::bin_op(self, rhs)?;
+                        ::std::result::Result::Ok(())
+                    }
+                }
+            }
+        } else {
+            quote! {
+                impl #impl_generics ::algebra::UnOp<::algebra::#op> for #this #where_clause {
+                    type Output = Self;
+                    type Err = ();
+
+                    fn un_op(&self) -> ::std::result::Result<Self, ()> {
+                        #res
+                    }
+                }
+
+                impl #impl_generics ::algebra::UnOpAssign<::algebra::#op> for #this
+                #where_clause
+                {
+                    type Err = ();
+
+                    fn un_op_assign(&mut self) -> ::std::result::Result<(), ()> {
+                        *self = ::algebra::UnOp::<::algebra::#op>::un_op(self)?;
+                        ::std::result::Result::Ok(())
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Implements the `bin_op` attribute.
+pub fn bin_op(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(attr, item, true) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Implements the `un_op` attribute.
+pub fn un_op(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(attr, item, false) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
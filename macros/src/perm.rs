@@ -0,0 +1,135 @@
+//! Implements the `perm!` macro.
+
+use std::collections::HashSet;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+    parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token::Paren,
+    Error, LitInt, Result, Token,
+};
+
+/// The two ways in which one can write down a permutation.
+enum PermInput {
+    /// One-line notation, listing the image of every point.
+    OneLine(Vec<LitInt>),
+
+    /// Cycle notation, optionally followed by the size of the permutation.
+    Cycles(Vec<Vec<LitInt>>, Option<LitInt>),
+}
+
+impl Parse for PermInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if !input.peek(Paren) {
+            let entries = Punctuated::<LitInt, Token![,]>::parse_terminated(input)?;
+            return Ok(Self::OneLine(entries.into_iter().collect()));
+        }
+
+        let mut cycles = Vec::new();
+        while input.peek(Paren) {
+            let content;
+            parenthesized!(content in input);
+
+            let mut cycle = Vec::new();
+            while !content.is_empty() {
+                cycle.push(content.parse()?);
+                if content.peek(Token![,]) {
+                    content.parse::<Token![,]>()?;
+                }
+            }
+
+            cycles.push(cycle);
+        }
+
+        let size = if input.parse::<Option<Token![;]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        if !input.is_empty() {
+            return Err(input.error("expected a cycle or `;`"));
+        }
+
+        Ok(Self::Cycles(cycles, size))
+    }
+}
+
+/// Validates that an entry of a permutation of a given size is in range and
+/// hasn't been seen before.
+fn check_entry(lit: &LitInt, size: usize, seen: &mut HashSet<usize>) -> Result<usize> {
+    let v = lit.base10_parse()?;
+
+    if v >= size {
+        Err(Error::new(
+            lit.span(),
+            format!(
+                "entry {} out of range for a permutation of size {}",
+                v, size
+            ),
+        ))
+    } else if !seen.insert(v) {
+        Err(Error::new(lit.span(), format!("repeated entry {}", v)))
+    } else {
+        Ok(v)
+    }
+}
+
+/// Returns the entries of the permutation in one-line notation.
+fn entries(input: PermInput) -> Result<Vec<usize>> {
+    let mut seen = HashSet::new();
+
+    match input {
+        PermInput::OneLine(lits) => lits
+            .iter()
+            .map(|lit| check_entry(lit, lits.len(), &mut seen))
+            .collect(),
+
+        PermInput::Cycles(cycles, size) => {
+            let size = match size {
+                Some(lit) => lit.base10_parse()?,
+                None => cycles
+                    .iter()
+                    .flatten()
+                    .map(LitInt::base10_parse::<usize>)
+                    .try_fold(0, |max, v| v.map(|v| max.max(v + 1)))?,
+            };
+
+            let mut entries: Vec<_> = (0..size).collect();
+            for cycle in &cycles {
+                let cycle = cycle
+                    .iter()
+                    .map(|lit| check_entry(lit, size, &mut seen))
+                    .collect::<Result<Vec<_>>>()?;
+
+                for (i, &v) in cycle.iter().enumerate() {
+                    entries[v] = cycle[(i + 1) % cycle.len()];
+                }
+            }
+
+            Ok(entries)
+        }
+    }
+}
+
+/// Expands the `perm!` macro.
+pub fn perm(input: TokenStream) -> TokenStream {
+    let entries = match syn::parse(input).and_then(entries) {
+        Ok(entries) => entries,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let n = entries.len();
+    (quote! {
+        // Safety: the entries were validated when the macro was expanded.
+        unsafe {
+            ::math::permutation::PermutationS::<#n>::new_unchecked(
+                ::math::storage::ArrayStorageE::from([#(#entries),*])
+            )
+        }
+    })
+    .into()
+}
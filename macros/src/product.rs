@@ -0,0 +1,237 @@
+//! Implements the `Magma` derive macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    punctuated::Punctuated, Data, DeriveInput, Error, Fields, Member, Path, Result, Token, Type,
+};
+
+/// Derives the componentwise structure of a struct under every operation
+/// listed in its `#[magma(...)]` attribute.
+pub fn derive_magma(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = match syn::parse(input) {
+        Ok(ast) => ast,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    match expand(&ast) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Returns the operations listed in the `#[magma(...)]` attributes.
+fn ops(ast: &DeriveInput) -> Result<Vec<Path>> {
+    let mut ops = Vec::new();
+    for attr in ast.attrs.iter().filter(|attr| attr.path.is_ident("magma")) {
+        let parser = Punctuated::<Path, Token![,]>::parse_terminated;
+        ops.extend(attr.parse_args_with(parser)?);
+    }
+
+    if ops.is_empty() {
+        return Err(Error::new_spanned(
+            &ast.ident,
+            "expected the operations to derive, as in `#[magma(Add, Mul)]`",
+        ));
+    }
+
+    Ok(ops)
+}
+
+/// Returns the members and types of the fields of a struct.
+fn fields(ast: &DeriveInput) -> Result<Vec<(Member, Type)>> {
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &ast.ident,
+                "`Magma` can only be derived for structs",
+            ))
+        }
+    };
+
+    Ok(match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|f| (Member::Named(f.ident.clone().unwrap()), f.ty.clone()))
+            .collect(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (Member::Unnamed(i.into()), f.ty.clone()))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    })
+}
+
+fn expand(ast: &DeriveInput) -> Result<TokenStream2> {
+    let ops = ops(ast)?;
+    let fields = fields(ast)?;
+    let members: Vec<_> = fields.iter().map(|(m, _)| m).collect();
+    let types: Vec<_> = fields.iter().map(|(_, t)| t).collect();
+
+    // We bound every distinct field type once. Repeating the first type in an
+    // equality bound would make the compiler recurse while normalizing it.
+    let mut distinct: Vec<String> = Vec::new();
+    let unique: Vec<_> = types
+        .iter()
+        .filter(|ty| {
+            let key = quote! { #ty }.to_string();
+            let new = !distinct.contains(&key);
+            if new {
+                distinct.push(key);
+            }
+            new
+        })
+        .collect();
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let preds: Vec<_> = where_clause
+        .map(|w| w.predicates.iter().collect())
+        .unwrap_or_default();
+    let this = quote! { #name #ty_generics };
+
+    // The bounds are higher-ranked, so that they're only checked when the impl
+    // is used. Otherwise, a struct without generics would fail to compile if
+    // a field didn't satisfy some property.
+    let bound = |tr: TokenStream2| {
+        quote! {
+            where #(#preds,)* #(for<'__a> #types: #tr,)*
+        }
+    };
+
+    let mut tokens = TokenStream2::new();
+    for op in &ops {
+        let magma = bound(quote! { ::algebra::Magma<#op> });
+        let pow_assoc = bound(quote! { ::algebra::PowAssociative<#op> });
+        let assoc = bound(quote! { ::algebra::Associative<#op> });
+        let comm = bound(quote! { ::algebra::Commutative<#op> });
+        let flexible = bound(quote! { ::algebra::Flexible<#op> });
+        let left_alt = bound(quote! { ::algebra::LeftAlternative<#op> });
+        let right_alt = bound(quote! { ::algebra::RightAlternative<#op> });
+        let medial = bound(quote! { ::algebra::Medial<#op> });
+        let self_distrib = bound(quote! { ::algebra::LeftDistributive<#op> });
+        let left_unital = bound(quote! { ::algebra::LeftUnital<#op> });
+        let right_unital = bound(quote! { ::algebra::RightUnital<#op> });
+
+        tokens.extend(quote! {
+            impl #impl_generics ::algebra::BinOp<#op> for #this #magma {
+                type Output = Self;
+                type Err = ();
+
+                fn bin_op(&self, rhs: &Self) -> ::std::result::Result<Self, ()> {
+                    ::std::result::Result::Ok(Self {
+                        #(#members: ::algebra::BinOp::<#op>::bin_op(
+                            &self.#members, &rhs.#members
+                        ).map_err(::std::mem::drop)?,)*
+                    })
+                }
+            }
+
+            impl #impl_generics ::algebra::BinOpAssign<#op> for #this #magma {
+                type Err = ();
+
+                fn bin_op_assign_lhs(&mut self, rhs: &Self) -> ::std::result::Result<(), ()> {
+                    #(::algebra::BinOpAssign::<#op>::bin_op_assign_lhs(
+                        &mut self.#members, &rhs.#members
+                    ).map_err(::std::mem::drop)?;)*
+                    ::std::result::Result::Ok(())
+                }
+
+                fn bin_op_assign_rhs(&self, rhs: &mut Self) -> ::std::result::Result<(), ()> {
+                    #(::algebra::BinOpAssign::<#op>::bin_op_assign_rhs(
+                        &self.#members, &mut rhs.#members
+                    ).map_err(::std::mem::drop)?;)*
+                    ::std::result::Result::Ok(())
+                }
+            }
+
+            impl #impl_generics ::algebra::Magma<#op> for #this #magma {}
+            impl #impl_generics ::algebra::PowAssociative<#op> for #this #pow_assoc {}
+            impl #impl_generics ::algebra::Associative<#op> for #this #assoc {}
+            impl #impl_generics ::algebra::Commutative<#op> for #this #comm {}
+            impl #impl_generics ::algebra::Flexible<#op> for #this #flexible {}
+            impl #impl_generics ::algebra::LeftAlternative<#op> for #this #left_alt {}
+            impl #impl_generics ::algebra::RightAlternative<#op> for #this #right_alt {}
+            impl #impl_generics ::algebra::Medial<#op> for #this #medial {}
+            impl #impl_generics ::algebra::LeftDistributive<#op> for #this #self_distrib {}
+
+            impl #impl_generics ::algebra::LeftUnital<#op> for #this #left_unital {
+                fn left_id() -> Self {
+                    Self {
+                        #(#members: <#types as ::algebra::LeftUnital<#op>>::left_id(),)*
+                    }
+                }
+            }
+
+            impl #impl_generics ::algebra::RightUnital<#op> for #this #right_unital {
+                fn right_id() -> Self {
+                    Self {
+                        #(#members: <#types as ::algebra::RightUnital<#op>>::right_id(),)*
+                    }
+                }
+            }
+        });
+
+        // The inverse operators of every field must agree, so we need at least
+        // one field to name them.
+        let (first, rest) = match unique.split_first() {
+            Some(split) => split,
+            None => continue,
+        };
+
+        let l_inv = quote! { <#first as ::algebra::LeftQuasigroup<#op>>::LInv };
+        let r_inv = quote! { <#first as ::algebra::RightQuasigroup<#op>>::RInv };
+        let inv = quote! { <#first as ::algebra::Group<#op>>::Inv };
+        let left_quasi = quote! { ::algebra::LeftQuasigroup<#op, LInv = #l_inv> };
+        let right_quasi = quote! { ::algebra::RightQuasigroup<#op, RInv = #r_inv> };
+        let group = quote! { ::algebra::Group<#op, Inv = #inv> };
+
+        tokens.extend(quote! {
+            impl #impl_generics ::algebra::LeftQuasigroup<#op> for #this
+            where
+                #(#preds,)*
+                for<'__a> #first: ::algebra::LeftQuasigroup<#op>,
+                #(for<'__a> #rest: #left_quasi,)*
+                for<'__a> #this: ::algebra::Magma<#l_inv>,
+            {
+                type LInv = #l_inv;
+            }
+
+            impl #impl_generics ::algebra::RightQuasigroup<#op> for #this
+            where
+                #(#preds,)*
+                for<'__a> #first: ::algebra::RightQuasigroup<#op>,
+                #(for<'__a> #rest: #right_quasi,)*
+                for<'__a> #this: ::algebra::Magma<#r_inv>,
+            {
+                type RInv = #r_inv;
+            }
+
+            impl #impl_generics ::algebra::Quandle<#op> for #this
+            where
+                #(#preds,)*
+                for<'__a> #first: ::algebra::Quandle<#op>,
+                #(for<'__a> #rest: ::algebra::Quandle<#op> + #left_quasi,)*
+                for<'__a> #this: ::algebra::Magma<#l_inv>,
+            {
+            }
+
+            impl #impl_generics ::algebra::Group<#op> for #this
+            where
+                #(#preds,)*
+                for<'__a> #first: ::algebra::Group<#op>,
+                #(for<'__a> #rest: #group + #left_quasi + #right_quasi,)*
+                for<'__a> #this: ::algebra::Magma<#l_inv> + ::algebra::Magma<#r_inv>,
+            {
+                type Inv = #inv;
+            }
+        });
+    }
+
+    Ok(tokens)
+}
@@ -0,0 +1,203 @@
+//! Implements the `StackStorage` derive macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, Member, Meta, NestedMeta, Result};
+
+/// Checks that a struct is either `#[repr(C)]` or `#[repr(transparent)]`, with
+/// no other modifiers. Modifiers like `packed` or `align` would break the
+/// layout of an array.
+fn check_repr(ast: &DeriveInput) -> Result<()> {
+    let mut found = false;
+    for attr in ast.attrs.iter().filter(|attr| attr.path.is_ident("repr")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "malformed `repr` attribute")),
+        };
+
+        for nested in &list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path))
+                    if path.is_ident("C") || path.is_ident("transparent") =>
+                {
+                    found = true;
+                }
+                _ => return Err(Error::new_spanned(
+                    nested,
+                    "`StackStorage` only supports a bare `#[repr(C)]` or `#[repr(transparent)]`",
+                )),
+            }
+        }
+    }
+
+    if found {
+        Ok(())
+    } else {
+        Err(Error::new_spanned(
+            &ast.ident,
+            "`StackStorage` requires `#[repr(C)]` or `#[repr(transparent)]`",
+        ))
+    }
+}
+
+fn expand(ast: &DeriveInput) -> Result<TokenStream2> {
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &ast.ident,
+                "`StackStorage` can only be derived for structs",
+            ))
+        }
+    };
+
+    check_repr(ast)?;
+
+    let (members, types): (Vec<_>, Vec<_>) = match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|f| (Member::Named(f.ident.clone().unwrap()), &f.ty))
+            .unzip(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (Member::Unnamed(i.into()), &f.ty))
+            .unzip(),
+        Fields::Unit => (Vec::new(), Vec::new()),
+    };
+
+    let first = match types.first() {
+        Some(first) => first,
+        None => {
+            return Err(Error::new_spanned(
+                &ast.ident,
+                "`StackStorage` requires at least one field",
+            ))
+        }
+    };
+
+    let rest = &types[1..];
+    let storage = quote! { ::math::storage };
+    let inner = quote! { <#first as #storage::Storage>::Inner };
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let preds: Vec<_> = where_clause
+        .map(|w| w.predicates.iter().collect())
+        .unwrap_or_default();
+    let this = quote! { #name #ty_generics };
+
+    // We bound every distinct field type once. Repeating the first type in an
+    // equality bound would make the compiler recurse while normalizing it.
+    let mut distinct: Vec<String> = vec![quote! { #first }.to_string()];
+    let others: Vec<_> = rest
+        .iter()
+        .filter(|ty| {
+            let key = quote! { #ty }.to_string();
+            let new = !distinct.contains(&key);
+            if new {
+                distinct.push(key);
+            }
+            new
+        })
+        .collect();
+
+    // Since every field is a stack storage of the same type, the struct has no
+    // padding, and the `repr` guarantees it's laid out as a single array.
+    let where_clause = quote! {
+        where
+            #(#preds,)*
+            #first: #storage::StackStorage,
+            #(#others: #storage::StackStorage<Inner = #inner>,)*
+    };
+
+    let (first_member, rest_members) = members.split_first().unwrap();
+    let into_iter = rest.iter().fold(
+        quote! { <#first as ::std::iter::IntoIterator>::IntoIter },
+        |acc, ty| quote! { ::std::iter::Chain<#acc, <#ty as ::std::iter::IntoIterator>::IntoIter> },
+    );
+
+    Ok(quote! {
+        impl #impl_generics ::std::ops::Index<usize> for #this #where_clause {
+            type Output = #inner;
+
+            fn index(&self, index: usize) -> &Self::Output {
+                #storage::ContiguousStorage::_index(self, index)
+            }
+        }
+
+        impl #impl_generics ::std::ops::IndexMut<usize> for #this #where_clause {
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+                #storage::ContiguousStorageMut::_index_mut(self, index)
+            }
+        }
+
+        impl #impl_generics ::std::borrow::Borrow<[#inner]> for #this #where_clause {
+            fn borrow(&self) -> &[#inner] {
+                #storage::StackStorage::_borrow(self)
+            }
+        }
+
+        impl #impl_generics ::std::borrow::BorrowMut<[#inner]> for #this #where_clause {
+            fn borrow_mut(&mut self) -> &mut [#inner] {
+                #storage::StackStorage::_borrow_mut(self)
+            }
+        }
+
+        impl #impl_generics ::std::iter::FromIterator<#inner> for #this #where_clause {
+            fn from_iter<I: ::std::iter::IntoIterator<Item = #inner>>(iter: I) -> Self {
+                #storage::StackStorage::_from_iter(iter)
+            }
+        }
+
+        impl #impl_generics ::std::iter::IntoIterator for #this #where_clause {
+            type Item = #inner;
+            type IntoIter = #into_iter;
+
+            fn into_iter(self) -> Self::IntoIter {
+                ::std::iter::IntoIterator::into_iter(self.#first_member)
+                    #(.chain(self.#rest_members))*
+            }
+        }
+
+        impl #impl_generics #storage::Storage for #this #where_clause {
+            type Inner = #inner;
+
+            const SIZE: ::std::option::Option<usize> = ::std::option::Option::Some(
+                0 #(+ <#types as #storage::StackStorage>::SIZE_U)*
+            );
+
+            fn get(&self, index: usize) -> ::std::option::Option<&Self::Inner> {
+                #storage::ContiguousStorage::_get(self, index)
+            }
+
+            fn len(&self) -> usize {
+                <Self as #storage::StackStorage>::SIZE_U
+            }
+        }
+
+        unsafe impl #impl_generics #storage::StorageMut for #this #where_clause {
+            fn get_mut(&mut self, index: usize) -> ::std::option::Option<&mut Self::Inner> {
+                #storage::ContiguousStorageMut::_get_mut(self, index)
+            }
+        }
+
+        // Safety: the struct is laid out as an array of its inner type.
+        unsafe impl #impl_generics #storage::StackStorage for #this #where_clause {}
+    })
+}
+
+/// Derives `StackStorage` and its boilerplate for a struct.
+pub fn derive_stack_storage(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = match syn::parse(input) {
+        Ok(ast) => ast,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    match expand(&ast) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
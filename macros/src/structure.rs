@@ -0,0 +1,505 @@
+//! Implements the `algebraic_structure` attribute macro.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    DeriveInput, Error, Expr, Ident, Result, Token,
+};
+
+/// A structure in the arguments of the attribute, like `Group<Add>`. Ring-like
+/// structures don't take an operation.
+struct Structure {
+    /// The name of the trait.
+    name: Ident,
+
+    /// The operation marker, if any.
+    op: Option<Ident>,
+}
+
+impl Parse for Structure {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse()?;
+        let op = if input.peek(Token![<]) {
+            input.parse::<Token![<]>()?;
+            let op = input.parse()?;
+            input.parse::<Token![>]>()?;
+            Some(op)
+        } else {
+            None
+        };
+
+        Ok(Self { name, op })
+    }
+}
+
+/// The arguments of the attribute: a list of structures, followed by the
+/// samples on which their laws are tested.
+struct StructureArgs {
+    /// The structures the type satisfies.
+    structures: Vec<Structure>,
+
+    /// An expression for some values, which may be turned into an iterator.
+    samples: Expr,
+}
+
+impl Parse for StructureArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut structures = Vec::new();
+        while !input.peek(Token![;]) {
+            structures.push(input.parse()?);
+            if !input.peek(Token![;]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        input.parse::<Token![;]>()?;
+        let samples_ident: Ident = input.parse()?;
+        if samples_ident != "samples" {
+            return Err(Error::new_spanned(samples_ident, "expected `samples`"));
+        }
+        input.parse::<Token![=]>()?;
+
+        Ok(Self {
+            structures,
+            samples: input.parse()?,
+        })
+    }
+}
+
+/// The number of samples a law takes.
+#[derive(Clone, Copy)]
+enum Arity {
+    /// The law is a statement about the type as a whole.
+    Nullary,
+
+    /// The law is tested on every sample.
+    Unary,
+
+    /// The law is tested on every pair of samples.
+    Binary,
+
+    /// The law is tested on every triple of samples.
+    Ternary,
+
+    /// The law is tested on every quadruple of samples.
+    Quaternary,
+}
+
+/// What we know about each structure.
+struct Info {
+    /// Whether the structure is a marker trait, which we can implement. The
+    /// others either have associated items that must be provided by hand, or
+    /// blanket implementations.
+    implement: bool,
+
+    /// The structures it immediately implies. These have the same operation,
+    /// unless one is specified.
+    implies: &'static [(&'static str, Option<&'static str>)],
+
+    /// The testers for the laws of the structure, and their arities.
+    laws: &'static [(&'static str, Arity)],
+}
+
+/// Returns what we know about a structure, and whether it takes an operation.
+fn info(name: &Ident) -> Result<(Info, bool)> {
+    use Arity::*;
+
+    let info = |implement, implies, laws| Info {
+        implement,
+        implies,
+        laws,
+    };
+
+    Ok(match name.to_string().as_str() {
+        "Magma" => (info(true, &[], &[]), true),
+        "PowAssociative" => (
+            info(true, &[("Magma", None)], &[("test_pow_assoc", Unary)]),
+            true,
+        ),
+        "Associative" => (
+            info(
+                true,
+                &[("PowAssociative", None)],
+                &[("test_assoc", Ternary)],
+            ),
+            true,
+        ),
+        "Commutative" => (
+            info(true, &[("Magma", None)], &[("test_comm", Binary)]),
+            true,
+        ),
+        "Flexible" => (
+            info(true, &[("Magma", None)], &[("test_flexible", Binary)]),
+            true,
+        ),
+        "LeftAlternative" => (
+            info(true, &[("Magma", None)], &[("test_left_alt", Binary)]),
+            true,
+        ),
+        "RightAlternative" => (
+            info(true, &[("Magma", None)], &[("test_right_alt", Binary)]),
+            true,
+        ),
+        "Alternative" => (
+            info(
+                false,
+                &[
+                    ("LeftAlternative", None),
+                    ("RightAlternative", None),
+                    ("Flexible", None),
+                ],
+                &[],
+            ),
+            true,
+        ),
+        "Medial" => (
+            info(true, &[("Magma", None)], &[("test_medial", Quaternary)]),
+            true,
+        ),
+        "LeftDistributive" => (
+            info(true, &[("Magma", None)], &[("test_self_distrib", Ternary)]),
+            true,
+        ),
+        "LeftUnital" => (
+            info(false, &[("Magma", None)], &[("test_left_id", Unary)]),
+            true,
+        ),
+        "RightUnital" => (
+            info(false, &[("Magma", None)], &[("test_right_id", Unary)]),
+            true,
+        ),
+        "Unital" => (
+            info(
+                false,
+                &[("LeftUnital", None), ("RightUnital", None)],
+                &[("test_id", Nullary)],
+            ),
+            true,
+        ),
+        "LeftAbsorbing" => (
+            info(false, &[("Magma", None)], &[("test_left_absorb", Unary)]),
+            true,
+        ),
+        "RightAbsorbing" => (
+            info(false, &[("Magma", None)], &[("test_right_absorb", Unary)]),
+            true,
+        ),
+        "Absorbing" => (
+            info(
+                false,
+                &[("LeftAbsorbing", None), ("RightAbsorbing", None)],
+                &[],
+            ),
+            true,
+        ),
+        "LeftQuasigroup" => (
+            info(false, &[("Magma", None)], &[("test_left_div", Binary)]),
+            true,
+        ),
+        "RightQuasigroup" => (
+            info(false, &[("Magma", None)], &[("test_right_div", Binary)]),
+            true,
+        ),
+        "Quasigroup" => (
+            info(
+                false,
+                &[("LeftQuasigroup", None), ("RightQuasigroup", None)],
+                &[],
+            ),
+            true,
+        ),
+        "Loop" => (
+            info(false, &[("Unital", None), ("Quasigroup", None)], &[]),
+            true,
+        ),
+        "LeftBolLoop" => (
+            info(true, &[("Loop", None)], &[("test_left_bol_loop", Ternary)]),
+            true,
+        ),
+        "RightBolLoop" => (
+            info(true, &[("Loop", None)], &[("test_right_bol_loop", Ternary)]),
+            true,
+        ),
+        "MoufangLoop" => (
+            info(false, &[("LeftBolLoop", None), ("RightBolLoop", None)], &[]),
+            true,
+        ),
+        "Rack" => (
+            info(
+                false,
+                &[("LeftDistributive", None), ("LeftQuasigroup", None)],
+                &[],
+            ),
+            true,
+        ),
+        "Quandle" => (
+            info(true, &[("Rack", None)], &[("test_idempotent", Unary)]),
+            true,
+        ),
+        "LeftInverseProperty" => (
+            info(true, &[("Loop", None)], &[("test_left_inv_prop", Binary)]),
+            true,
+        ),
+        "RightInverseProperty" => (
+            info(true, &[("Loop", None)], &[("test_right_inv_prop", Binary)]),
+            true,
+        ),
+        "InverseProperty" => (
+            info(
+                false,
+                &[
+                    ("LeftInverseProperty", None),
+                    ("RightInverseProperty", None),
+                ],
+                &[],
+            ),
+            true,
+        ),
+        "AutomorphicInverseProperty" => (
+            info(
+                true,
+                &[("InverseProperty", None)],
+                &[("test_auto_inv", Binary)],
+            ),
+            true,
+        ),
+        "CommutativeMoufangLoop" => (
+            info(false, &[("MoufangLoop", None), ("Commutative", None)], &[]),
+            true,
+        ),
+        "Monoid" => (
+            info(false, &[("Unital", None), ("Associative", None)], &[]),
+            true,
+        ),
+        "CommutativeMonoid" => (
+            info(false, &[("Monoid", None), ("Commutative", None)], &[]),
+            true,
+        ),
+        "Group" => (
+            info(
+                false,
+                &[("Associative", None), ("Loop", None)],
+                &[("test_two_sided_inv", Unary)],
+            ),
+            true,
+        ),
+        "InverseSemigroup" => (
+            info(
+                false,
+                &[("Associative", None)],
+                &[
+                    ("test_gen_inv", Unary),
+                    ("test_gen_inv_inv", Unary),
+                    ("test_idempotents_commute", Binary),
+                ],
+            ),
+            true,
+        ),
+        "Ring" => (
+            info(
+                true,
+                &[
+                    ("Group", Some("Add")),
+                    ("Commutative", Some("Add")),
+                    ("Monoid", Some("Mul")),
+                ],
+                &[
+                    ("test_left_distrib", Ternary),
+                    ("test_right_distrib", Ternary),
+                ],
+            ),
+            false,
+        ),
+        "DivisionRing" => (info(true, &[("Ring", None)], &[("test_rec", Unary)]), false),
+        "Field" => (
+            info(
+                false,
+                &[("DivisionRing", None), ("Commutative", Some("Mul"))],
+                &[],
+            ),
+            false,
+        ),
+        "EuclideanDomain" => (
+            info(
+                false,
+                &[("Ring", None), ("Commutative", Some("Mul"))],
+                &[("test_div_rem", Binary)],
+            ),
+            false,
+        ),
+        _ => return Err(Error::new_spanned(name, "unknown algebraic structure")),
+    })
+}
+
+/// Returns the path to a structure, under a given operation.
+fn path(name: &Ident, op: &Option<Ident>) -> TokenStream2 {
+    match op {
+        Some(op) => quote! { ::algebra::#name<::algebra::#op> },
+        None => quote! { ::algebra::#name },
+    }
+}
+
+/// Converts a type name to snake case.
+fn snake_case(name: &Ident) -> String {
+    let mut res = String::new();
+    for (i, c) in name.to_string().chars().enumerate() {
+        if c.is_uppercase() && i != 0 {
+            res.push('_');
+        }
+
+        res.extend(c.to_lowercase());
+    }
+
+    res
+}
+
+fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream2> {
+    let args: StructureArgs = syn::parse(attr)?;
+    let ast: DeriveInput = syn::parse(item)?;
+
+    // Finds every structure implied by the arguments, in order.
+    let mut closure: Vec<(Ident, Option<Ident>, Info)> = Vec::new();
+    let mut stack: Vec<(Ident, Option<Ident>)> = args
+        .structures
+        .into_iter()
+        .map(|s| (s.name, s.op))
+        .rev()
+        .collect();
+
+    while let Some((name, op)) = stack.pop() {
+        let (info, takes_op) = info(&name)?;
+        if takes_op != op.is_some() {
+            return Err(Error::new_spanned(
+                &name,
+                if takes_op {
+                    "expected an operation, as in `Group<Add>`"
+                } else {
+                    "this structure doesn't take an operation"
+                },
+            ));
+        }
+
+        let key = (name.to_string(), op.as_ref().map(Ident::to_string));
+        if closure
+            .iter()
+            .any(|(n, o, _)| (n.to_string(), o.as_ref().map(Ident::to_string)) == key)
+        {
+            continue;
+        }
+
+        for &(implied, implied_op) in info.implies.iter().rev() {
+            let implied_op = implied_op
+                .map(|o| Ident::new(o, name.span()))
+                .or_else(|| op.clone());
+            stack.push((Ident::new(implied, name.span()), implied_op));
+        }
+
+        closure.push((name, op, info));
+    }
+
+    let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let mut tokens = quote! { #ast };
+
+    let mut bounds = Vec::new();
+    let mut laws = Vec::new();
+    for (structure, op, info) in &closure {
+        let path = path(structure, op);
+        if info.implement {
+            tokens.extend(quote! {
+                impl #impl_generics #path for #name #ty_generics #where_clause {}
+            });
+        }
+
+        let desc = match op {
+            Some(op) => format!("{}<{}>", structure, op),
+            None => structure.to_string(),
+        };
+
+        for &(law, arity) in info.laws {
+            let law = Ident::new(law, structure.span());
+            let msg = format!("{}::{} failed", desc, law);
+            laws.push(match arity {
+                Arity::Nullary => quote! {
+                    assert!(<T as #path>::#law(), #msg);
+                },
+                Arity::Unary => quote! {
+                    for a in samples {
+                        assert!(<T as #path>::#law(a), "{} for {:?}", #msg, a);
+                    }
+                },
+                Arity::Binary => quote! {
+                    for a in samples {
+                        for b in samples {
+                            assert!(<T as #path>::#law(a, b), "{} for {:?}, {:?}", #msg, a, b);
+                        }
+                    }
+                },
+                Arity::Quaternary => quote! {
+                    for a in samples {
+                        for b in samples {
+                            for c in samples {
+                                for d in samples {
+                                    assert!(
+                                        <T as #path>::#law(a, b, c, d),
+                                        "{} for {:?}, {:?}, {:?}, {:?}", #msg, a, b, c, d
+                                    );
+                                }
+                            }
+                        }
+                    }
+                },
+                Arity::Ternary => quote! {
+                    for a in samples {
+                        for b in samples {
+                            for c in samples {
+                                assert!(
+                                    <T as #path>::#law(a, b, c),
+                                    "{} for {:?}, {:?}, {:?}", #msg, a, b, c
+                                );
+                            }
+                        }
+                    }
+                },
+            });
+        }
+
+        bounds.push(path);
+    }
+
+    let samples = &args.samples;
+    let module = format_ident!("{}_laws", snake_case(name));
+    tokens.extend(quote! {
+        #[cfg(test)]
+        mod #module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[test]
+            fn laws() {
+                fn check<T>(samples: &[T])
+                where
+                    T: ::std::fmt::Debug + ::std::cmp::PartialEq #(+ #bounds)*,
+                {
+                    #(#laws)*
+                }
+
+                let samples: ::std::vec::Vec<_> =
+                    ::std::iter::IntoIterator::into_iter(#samples).collect();
+                check(&samples);
+            }
+        }
+    });
+
+    Ok(tokens)
+}
+
+/// Implements the `algebraic_structure` attribute.
+pub fn algebraic_structure(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match expand(attr, item) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,434 @@
+//! Declares an arbitrary precision natural number type.
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    ops::{Add, Mul},
+};
+
+use algebra::{algebraic_structure, bin_op, LeftUnital, RightUnital};
+
+/// An arbitrary precision natural number. This is stored as a list of base
+/// 2<sup>64</sup> digits, from least to most significant, with no trailing
+/// zeros.
+#[algebraic_structure(
+    CommutativeMonoid<Add>, CommutativeMonoid<Mul>,
+    Alternative<Add>, Alternative<Mul>, Medial<Add>, Medial<Mul>;
+    samples = [0, 1, 2, 3, u64::MAX]
+        .iter()
+        .map(|&n| BigNat::from(n))
+        .chain([BigNat::from(u128::MAX), BigNat::from(3u64).pow(50)])
+)]
+#[bin_op(Add, Mul, by_ref)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigNat(Vec<u64>);
+
+impl From<u64> for BigNat {
+    fn from(n: u64) -> Self {
+        let mut res = Self(vec![n]);
+        res.normalize();
+        res
+    }
+}
+
+impl From<u128> for BigNat {
+    fn from(n: u128) -> Self {
+        let mut res = Self(vec![n as u64, (n >> 64) as u64]);
+        res.normalize();
+        res
+    }
+}
+
+impl From<usize> for BigNat {
+    fn from(n: usize) -> Self {
+        Self::from(n as u64)
+    }
+}
+
+impl BigNat {
+    /// Returns zero.
+    pub fn zero() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns one.
+    pub fn one() -> Self {
+        Self(vec![1])
+    }
+
+    /// Returns whether the number is zero.
+    pub fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the digits of the number in base 2<sup>64</sup>, from least to
+    /// most significant.
+    pub fn digits(&self) -> &[u64] {
+        &self.0
+    }
+
+    /// Removes any trailing zero digits.
+    fn normalize(&mut self) {
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+    }
+
+    /// Returns the number as a `u128`, if it fits.
+    pub fn to_u128(&self) -> Option<u128> {
+        match self.0.len() {
+            0 => Some(0),
+            1 => Some(self.0[0] as u128),
+            2 => Some(self.0[0] as u128 | (self.0[1] as u128) << 64),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of significant bits.
+    pub fn bits(&self) -> usize {
+        match self.0.last() {
+            Some(d) => 64 * self.0.len() - d.leading_zeros() as usize,
+            None => 0,
+        }
+    }
+
+    /// Returns the bit at a given position.
+    fn bit(&self, i: usize) -> bool {
+        self.0.get(i / 64).is_some_and(|d| (d >> (i % 64)) & 1 == 1)
+    }
+
+    /// Adds a small number to `self`.
+    pub fn add_small(&mut self, n: u64) {
+        let mut carry = n;
+        for d in &mut self.0 {
+            if carry == 0 {
+                return;
+            }
+
+            let (sum, overflow) = d.overflowing_add(carry);
+            *d = sum;
+            carry = overflow as u64;
+        }
+
+        if carry != 0 {
+            self.0.push(carry);
+        }
+    }
+
+    /// Multiplies `self` by a small number.
+    pub fn mul_small(&mut self, n: u64) {
+        if n == 0 {
+            self.0.clear();
+            return;
+        }
+
+        let mut carry = 0;
+        for d in &mut self.0 {
+            let prod = *d as u128 * n as u128 + carry as u128;
+            *d = prod as u64;
+            carry = (prod >> 64) as u64;
+        }
+
+        if carry != 0 {
+            self.0.push(carry);
+        }
+    }
+
+    /// Divides `self` by a small number, and returns the remainder.
+    ///
+    /// # Panics
+    /// This method will panic if `n` is zero.
+    pub fn div_rem_small(&mut self, n: u64) -> u64 {
+        assert_ne!(n, 0, "division by zero");
+
+        let mut rem = 0u128;
+        for d in self.0.iter_mut().rev() {
+            let cur = rem << 64 | *d as u128;
+            *d = (cur / n as u128) as u64;
+            rem = cur % n as u128;
+        }
+
+        self.normalize();
+        rem as u64
+    }
+
+    /// Returns the remainder of `self` when divided by a small number.
+    ///
+    /// # Panics
+    /// This method will panic if `n` is zero.
+    pub fn rem_small(&self, n: u64) -> u64 {
+        assert_ne!(n, 0, "division by zero");
+
+        self.0
+            .iter()
+            .rev()
+            .fold(0u128, |rem, &d| (rem << 64 | d as u128) % n as u128) as u64
+    }
+
+    /// Shifts `self` left by a single bit.
+    fn shl_one(&mut self) {
+        let mut carry = 0;
+        for d in &mut self.0 {
+            let next = *d >> 63;
+            *d = *d << 1 | carry;
+            carry = next;
+        }
+
+        if carry != 0 {
+            self.0.push(carry);
+        }
+    }
+
+    /// Subtracts `rhs` from `self`, or returns `false` and leaves `self`
+    /// unchanged if the result would be negative.
+    pub fn sub_assign(&mut self, rhs: &Self) -> bool {
+        if *self < *rhs {
+            return false;
+        }
+
+        let mut borrow = false;
+        for (i, d) in self.0.iter_mut().enumerate() {
+            let r = rhs.0.get(i).copied().unwrap_or(0);
+            if r == 0 && !borrow && i >= rhs.0.len() {
+                break;
+            }
+
+            let (diff, o1) = d.overflowing_sub(r);
+            let (diff, o2) = diff.overflowing_sub(borrow as u64);
+            *d = diff;
+            borrow = o1 || o2;
+        }
+
+        self.normalize();
+        true
+    }
+
+    /// Returns the quotient and remainder of `self` divided by `rhs`.
+    ///
+    /// # Panics
+    /// This method will panic if `rhs` is zero.
+    pub fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        assert!(!rhs.is_zero(), "division by zero");
+
+        if let [n] = rhs.0[..] {
+            let mut quot = self.clone();
+            let rem = quot.div_rem_small(n);
+            return (quot, Self::from(rem));
+        }
+
+        // Binary long division.
+        let mut quot = Self(vec![0; self.0.len()]);
+        let mut rem = Self::zero();
+        for i in (0..self.bits()).rev() {
+            rem.shl_one();
+            if self.bit(i) {
+                rem.add_small(1);
+            }
+
+            if rem.sub_assign(rhs) {
+                quot.0[i / 64] |= 1 << (i % 64);
+            }
+        }
+
+        quot.normalize();
+        (quot, rem)
+    }
+
+    /// Raises `self` to a given power.
+    pub fn pow(&self, mut e: u32) -> Self {
+        let mut res = Self::one();
+        let mut x = self.clone();
+
+        while e != 0 {
+            if e % 2 == 1 {
+                res = &res * &x;
+            }
+
+            e /= 2;
+            if e != 0 {
+                x = &x * &x;
+            }
+        }
+
+        res
+    }
+}
+
+impl PartialOrd for BigNat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigNat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl Display for BigNat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        /// The largest power of 10 that fits in a `u64`.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+        if self.is_zero() {
+            return f.pad_integral(true, "", "0");
+        }
+
+        let mut chunks = Vec::new();
+        let mut n = self.clone();
+        while !n.is_zero() {
+            chunks.push(n.div_rem_small(CHUNK));
+        }
+
+        let mut s = chunks.pop().unwrap().to_string();
+        for c in chunks.iter().rev() {
+            s.push_str(&format!("{:019}", c));
+        }
+
+        f.pad_integral(true, "", &s)
+    }
+}
+
+impl<'a> Add<&'a BigNat> for &'a BigNat {
+    type Output = BigNat;
+
+    fn add(self, rhs: &'a BigNat) -> BigNat {
+        let (long, short) = if self.0.len() >= rhs.0.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut res = long.clone();
+        let mut carry = false;
+        for (i, d) in res.0.iter_mut().enumerate() {
+            let s = short.0.get(i).copied().unwrap_or(0);
+            if s == 0 && !carry && i >= short.0.len() {
+                break;
+            }
+
+            let (sum, o1) = d.overflowing_add(s);
+            let (sum, o2) = sum.overflowing_add(carry as u64);
+            *d = sum;
+            carry = o1 || o2;
+        }
+
+        if carry {
+            res.0.push(1);
+        }
+
+        res
+    }
+}
+
+impl Add for BigNat {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        &self + &rhs
+    }
+}
+
+impl<'a> Mul<&'a BigNat> for &'a BigNat {
+    type Output = BigNat;
+
+    fn mul(self, rhs: &'a BigNat) -> BigNat {
+        if self.is_zero() || rhs.is_zero() {
+            return BigNat::zero();
+        }
+
+        let mut res = vec![0u64; self.0.len() + rhs.0.len()];
+        for (i, &a) in self.0.iter().enumerate() {
+            let mut carry = 0u128;
+            for (j, &b) in rhs.0.iter().enumerate() {
+                let cur = res[i + j] as u128 + a as u128 * b as u128 + carry;
+                res[i + j] = cur as u64;
+                carry = cur >> 64;
+            }
+
+            res[i + rhs.0.len()] = carry as u64;
+        }
+
+        let mut res = BigNat(res);
+        res.normalize();
+        res
+    }
+}
+
+impl Mul for BigNat {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        &self * &rhs
+    }
+}
+
+impl LeftUnital<algebra::Add> for BigNat {
+    fn left_id() -> Self {
+        Self::zero()
+    }
+}
+
+impl RightUnital<algebra::Add> for BigNat {
+    fn right_id() -> Self {
+        Self::zero()
+    }
+}
+
+impl LeftUnital<algebra::Mul> for BigNat {
+    fn left_id() -> Self {
+        Self::one()
+    }
+}
+
+impl RightUnital<algebra::Mul> for BigNat {
+    fn right_id() -> Self {
+        Self::one()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::{BinOp, BinOpAssign};
+
+    #[test]
+    fn arithmetic() {
+        let a = BigNat::from(u64::MAX);
+        let b = &a + &BigNat::one();
+        assert_eq!(b.digits(), &[0, 1]);
+        assert_eq!(b.to_u128(), Some(1 << 64));
+
+        let c = &b * &b;
+        assert_eq!(c.digits(), &[0, 0, 1]);
+        assert_eq!(c.to_u128(), None);
+
+        let (q, r) = (&c + &BigNat::from(5u64)).div_rem(&a);
+        assert_eq!(&(&q * &a) + &r, &c + &BigNat::from(5u64));
+        assert!(r < a);
+
+        assert_eq!(BinOp::<algebra::Mul>::bin_op(&a, &b), Ok(&a * &b));
+        let mut d = BigNat::one();
+        BinOpAssign::<algebra::Add>::bin_op_assign_lhs(&mut d, &a).unwrap();
+        assert_eq!(d, b);
+    }
+
+    #[test]
+    fn display() {
+        let mut fact = BigNat::one();
+        for i in 1..=30 {
+            fact.mul_small(i);
+        }
+
+        assert_eq!(fact.to_string(), "265252859812191058636308480000000");
+        assert_eq!(BigNat::zero().to_string(), "0");
+        assert_eq!(
+            BigNat::from(2u64).pow(100).rem_small(1_000_000_007),
+            976371285
+        );
+    }
+}
@@ -0,0 +1,412 @@
+//! Implements [isomorphism](https://en.wikipedia.org/wiki/Isomorphism) testing,
+//! automorphism groups, and canonical labellings of finite magmas.
+//!
+//! All of these are backtracking searches, which propagate the images of
+//! products as soon as their factors have been mapped. This makes them fast on
+//! structures generated by a few elements, like groups, though they take
+//! exponential time in the worst case.
+
+use std::cmp::Ordering;
+
+use super::CayleyMagma;
+use crate::{
+    permutation::{PermGroupD, Permutation, PermutationD},
+    storage::{OwnedStorage, Size, Storage},
+};
+
+/// Returns a list of invariants for every element of a magma, which must be
+/// preserved by any isomorphism.
+fn invariants<S: Storage<Inner = usize>>(m: &CayleyMagma<S>) -> Vec<[usize; 6]> {
+    let n = m.order();
+    let mut squares = vec![0; n];
+    for x in 0..n {
+        squares[m.op(x, x)] += 1;
+    }
+
+    (0..n)
+        .map(|a| {
+            let count = |f: &dyn Fn(usize) -> bool| (0..n).filter(|&x| f(x)).count();
+
+            // The number of distinct left powers a, a · a, (a · a) · a, …
+            let mut seen = vec![false; n];
+            let (mut p, mut powers) = (a, 0);
+            while !seen[p] {
+                seen[p] = true;
+                powers += 1;
+                p = m.op(p, a);
+            }
+
+            [
+                (m.op(a, a) == a) as usize,
+                count(&|x| m.op(a, x) == x),
+                count(&|x| m.op(x, a) == x),
+                count(&|x| m.op(a, x) == m.op(x, a)),
+                squares[a],
+                powers,
+            ]
+        })
+        .collect()
+}
+
+/// The state of a search for isomorphisms from one magma to another.
+struct Search<'a, S: Storage<Inner = usize>, T: Storage<Inner = usize>> {
+    /// The domain of the isomorphism.
+    a: &'a CayleyMagma<S>,
+
+    /// The codomain of the isomorphism.
+    b: &'a CayleyMagma<T>,
+
+    /// The invariants of the elements of the domain.
+    inv_a: Vec<[usize; 6]>,
+
+    /// The invariants of the elements of the codomain.
+    inv_b: Vec<[usize; 6]>,
+
+    /// The image of every element of the domain, if set.
+    map: Vec<Option<usize>>,
+
+    /// Whether every element of the codomain is an image.
+    used: Vec<bool>,
+
+    /// The elements of the domain that have been mapped, in order.
+    trail: Vec<usize>,
+}
+
+impl<'a, S: Storage<Inner = usize>, T: Storage<Inner = usize>> Search<'a, S, T> {
+    /// Initializes a search. Returns `None` if the invariants already rule out
+    /// an isomorphism.
+    fn new(a: &'a CayleyMagma<S>, b: &'a CayleyMagma<T>) -> Option<Self> {
+        let (inv_a, inv_b) = (invariants(a), invariants(b));
+        let (mut sorted_a, mut sorted_b) = (inv_a.clone(), inv_b.clone());
+        sorted_a.sort_unstable();
+        sorted_b.sort_unstable();
+        if sorted_a != sorted_b {
+            return None;
+        }
+
+        let n = a.order();
+        Some(Self {
+            a,
+            b,
+            inv_a,
+            inv_b,
+            map: vec![None; n],
+            used: vec![false; n],
+            trail: Vec::with_capacity(n),
+        })
+    }
+
+    /// Maps `x` to `y`, along with every product this forces. Returns whether
+    /// this was consistent. Either way, [`Self::undo`] should be used to
+    /// backtrack.
+    fn assign(&mut self, x: usize, y: usize) -> bool {
+        let mut queue = vec![(x, y)];
+
+        while let Some((x, y)) = queue.pop() {
+            if let Some(z) = self.map[x] {
+                if z != y {
+                    return false;
+                }
+
+                continue;
+            }
+
+            if self.used[y] || self.inv_a[x] != self.inv_b[y] {
+                return false;
+            }
+
+            self.map[x] = Some(y);
+            self.used[y] = true;
+            self.trail.push(x);
+
+            for &z in &self.trail {
+                let w = self.map[z].unwrap();
+                queue.push((self.a.op(x, z), self.b.op(y, w)));
+                queue.push((self.a.op(z, x), self.b.op(w, y)));
+            }
+        }
+
+        true
+    }
+
+    /// Unmaps every element, until only the first `len` mapped ones remain.
+    fn undo(&mut self, len: usize) {
+        while self.trail.len() > len {
+            let x = self.trail.pop().unwrap();
+            self.used[self.map[x].unwrap()] = false;
+            self.map[x] = None;
+        }
+    }
+
+    /// Returns the first element that hasn't been mapped.
+    fn next(&self) -> Option<usize> {
+        self.map.iter().position(Option::is_none)
+    }
+
+    /// Extends the current partial map into an isomorphism, if possible.
+    fn find(&mut self) -> bool {
+        let x = match self.next() {
+            Some(x) => x,
+            None => return true,
+        };
+
+        let len = self.trail.len();
+        for y in 0..self.map.len() {
+            if !self.used[y] && self.assign(x, y) && self.find() {
+                return true;
+            }
+
+            self.undo(len);
+        }
+
+        false
+    }
+
+    /// Returns the isomorphism found.
+    fn perm(&self) -> PermutationD {
+        self.map.iter().map(|y| y.unwrap()).collect()
+    }
+}
+
+/// Returns an isomorphism between two finite magmas, if any. This is a
+/// bijection `p` such that `p[a · b] = p[a] · p[b]` for all `a, b`.
+pub fn are_isomorphic<S: Storage<Inner = usize>, T: Storage<Inner = usize>>(
+    a: &CayleyMagma<S>,
+    b: &CayleyMagma<T>,
+) -> Option<PermutationD> {
+    if a.order() != b.order() {
+        return None;
+    }
+
+    let mut search = Search::new(a, b)?;
+    search.find().then(|| search.perm())
+}
+
+impl<S: Storage<Inner = usize>> CayleyMagma<S> {
+    /// Returns the group of automorphisms of the magma, as a permutation group
+    /// on its elements.
+    ///
+    /// We walk down the branch of the search tree corresponding to the
+    /// identity. At every level, we look for a single automorphism mapping the
+    /// new point to each candidate outside of its orbit under the subgroup
+    /// found so far. These, together with the generators of the stabilizer of
+    /// the point, generate the stabilizer at this level.
+    pub fn automorphism_group(&self) -> PermGroupD {
+        /// Finds generators for the automorphisms fixing every mapped point.
+        fn descend<S: Storage<Inner = usize>>(
+            search: &mut Search<'_, S, S>,
+            gens: &mut Vec<PermutationD>,
+        ) {
+            let x = match search.next() {
+                Some(x) => x,
+                None => return,
+            };
+
+            let size = Size::from_usize(search.map.len());
+            let len = search.trail.len();
+            let fixed = search.trail.clone();
+            search.assign(x, x);
+            descend(search, gens);
+            search.undo(len);
+
+            let mut orbit = Vec::new();
+            let mut stale = true;
+            for y in 0..search.map.len() {
+                if y == x || search.used[y] {
+                    continue;
+                }
+
+                if stale {
+                    let group = PermGroupD::new(size, gens.clone());
+                    orbit = group.pointwise_stabilizer(&fixed).orbit(x);
+                    stale = false;
+                }
+
+                if !orbit.contains(&y) && search.assign(x, y) && search.find() {
+                    gens.push(search.perm());
+                    stale = true;
+                }
+
+                search.undo(len);
+            }
+        }
+
+        let size = Size::from_usize(self.order());
+        let mut gens = Vec::new();
+        if let Some(mut search) = Search::new(self, self) {
+            descend(&mut search, &mut gens);
+        }
+
+        PermGroupD::new(size, gens)
+    }
+
+    /// Labels the elements in the order they're found, starting from some seed
+    /// elements, and multiplying every element found by every previous one.
+    /// Returns the elements in order, and their labels.
+    fn close(&self, seeds: &[usize]) -> (Vec<usize>, Vec<Option<usize>>) {
+        let n = self.order();
+        let mut order = Vec::with_capacity(n);
+        let mut labels = vec![None; n];
+        let mut k = 0;
+
+        for &s in seeds {
+            labels[s] = Some(order.len());
+            order.push(s);
+
+            while k < order.len() {
+                let x = order[k];
+                for i in 0..=k {
+                    let y = order[i];
+                    for p in [self.op(y, x), self.op(x, y)] {
+                        if labels[p].is_none() {
+                            labels[p] = Some(order.len());
+                            order.push(p);
+                        }
+                    }
+                }
+
+                k += 1;
+            }
+        }
+
+        (order, labels)
+    }
+
+    /// Returns a canonical labelling of the magma. This is a permutation `p`
+    /// such that relabelling each element `a` as `p[a]` yields the same table
+    /// for any two isomorphic magmas.
+    ///
+    /// Every labelling considered is found by [closing](Self::close) a list of
+    /// seeds, where each new seed is chosen among the unlabelled elements with
+    /// the least invariants. The canonical one gives the lexicographically
+    /// least table.
+    pub fn canonical_labelling(&self) -> PermutationD {
+        /// Finds the best labelling extending a given list of seeds.
+        fn search<S: Storage<Inner = usize>>(
+            m: &CayleyMagma<S>,
+            inv: &[[usize; 6]],
+            seeds: &mut Vec<usize>,
+            best: &mut Option<(Vec<usize>, Vec<Option<usize>>)>,
+        ) {
+            let n = m.order();
+            let (order, labels) = m.close(seeds);
+
+            if order.len() == n {
+                let table: Vec<_> = (0..n * n)
+                    .map(|k| labels[m.op(order[k / n], order[k % n])].unwrap())
+                    .collect();
+
+                let better = best
+                    .as_ref()
+                    .is_none_or(|(t, _)| table.cmp(t) == Ordering::Less);
+                if better {
+                    *best = Some((table, labels));
+                }
+
+                return;
+            }
+
+            let min = (0..n)
+                .filter(|&x| labels[x].is_none())
+                .map(|x| inv[x])
+                .min();
+            for x in 0..n {
+                if labels[x].is_none() && Some(inv[x]) == min {
+                    seeds.push(x);
+                    search(m, inv, seeds, best);
+                    seeds.pop();
+                }
+            }
+        }
+
+        let mut best = None;
+        search(self, &invariants(self), &mut Vec::new(), &mut best);
+        let labels = best.map_or_else(Vec::new, |(_, labels)| labels);
+        labels.into_iter().map(Option::unwrap).collect()
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> CayleyMagma<S> {
+    /// Relabels every element `a` of the magma as `p[a]`.
+    ///
+    /// # Panics
+    /// This method will panic if the permutation and the magma have different
+    /// sizes.
+    pub fn relabel<A: Storage<Inner = usize>>(&self, p: &Permutation<A>) -> Self {
+        assert_eq!(p.len(), self.order(), "size mismatch");
+        let mut inv = vec![0; p.len()];
+        for (i, v) in p.iter().enumerate() {
+            inv[v] = i;
+        }
+
+        Self::from_fn(self.order(), |a, b| p[self.op(inv[a], inv[b])])
+    }
+
+    /// Returns the canonical form of the magma, obtained by relabelling it
+    /// using [`Self::canonical_labelling`]. Two magmas are isomorphic if and
+    /// only if their canonical forms are equal.
+    pub fn canonical_form(&self) -> Self {
+        self.relabel(&self.canonical_labelling())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cayley::{CayleyMagmaD, CayleyMagmaS},
+        cayley_table,
+        random::Rng,
+    };
+
+    /// The Klein four-group.
+    const KLEIN: CayleyMagmaS<4> =
+        cayley_table![[0, 1, 2, 3], [1, 0, 3, 2], [2, 3, 0, 1], [3, 2, 1, 0],];
+
+    #[test]
+    fn isomorphism() {
+        let xor = CayleyMagmaS::<4>::from_fn(4, |a, b| a ^ b);
+        let z4 = CayleyMagmaD::from_fn(4, |a, b| (a + b) % 4);
+        assert!(are_isomorphic(&KLEIN, &z4).is_none());
+
+        let p = are_isomorphic(&KLEIN, &xor).unwrap();
+        for a in 0..4 {
+            for b in 0..4 {
+                assert_eq!(p[KLEIN.op(a, b)], xor.op(p[a], p[b]));
+            }
+        }
+    }
+
+    #[test]
+    fn automorphism_group() {
+        let orders = [
+            (CayleyMagmaD::from_fn(4, |a, b| a ^ b), 6u64),
+            (CayleyMagmaD::from_fn(8, |a, b| a ^ b), 168),
+            (CayleyMagmaD::from_fn(5, |a, b| (a + b) % 5), 4),
+            (CayleyMagmaD::from_fn(3, |a, _| a), 6),
+        ];
+
+        for (m, order) in &orders {
+            let aut = m.automorphism_group();
+            assert_eq!(aut.order().to_u128(), Some(*order as u128));
+            for g in aut.generators() {
+                assert_eq!(&m.relabel(g), m);
+            }
+        }
+    }
+
+    #[test]
+    fn canonical_form() {
+        let mut rng = Rng::new(3);
+        let z8 = CayleyMagmaD::from_fn(8, |a, b| (a + b) % 8);
+        let z2z4 = CayleyMagmaD::from_fn(8, |a, b| ((a ^ b) & 1) | (((a / 2 + b / 2) % 4) * 2));
+        let (c8, c2c4) = (z8.canonical_form(), z2z4.canonical_form());
+        assert_ne!(c8, c2c4);
+
+        for _ in 0..5 {
+            let p = PermutationD::random(Size::from_usize(8), &mut rng);
+            assert_eq!(z8.relabel(&p).canonical_form(), c8);
+            assert_eq!(z2z4.relabel(&p).canonical_form(), c2c4);
+        }
+    }
+}
@@ -0,0 +1,502 @@
+//! Implements [Latin squares](https://en.wikipedia.org/wiki/Latin_square), which
+//! are exactly the Cayley tables of finite quasigroups.
+
+use std::ptr;
+
+use algebra::{BinOp, BinOpAssign, Bws, Div, LDiv, LeftQuasigroup, Magma, Mul, RightQuasigroup};
+
+use super::{are_isomorphic, CayleyMagma, CayleyMagmaD};
+use crate::{
+    permutation::PermutationD,
+    random::Rng,
+    storage::{ArrayStorage, ArrayStorageE, OwnedStorage, Size, Storage, VecStorageE},
+};
+
+/// A Latin square of order `n`. This is an `n × n` table on the symbols `0..n`,
+/// such that every symbol appears exactly once in every row and every column.
+///
+/// We think of it as the table of a quasigroup. Along with it, we store the
+/// tables for left and right division, so that its elements implement
+/// [`Quasigroup`](algebra::Quasigroup) under [`Mul`], [`Div`], [`LDiv`], and
+/// their [`Bws`] counterparts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LatinSquare<S: Storage<Inner = usize>> {
+    /// The table of the square itself.
+    mul: CayleyMagma<S>,
+
+    /// The table for left division, so that `a · (a \ b) = b`.
+    ldiv: CayleyMagma<S>,
+
+    /// The table for right division, so that `(b / a) · a = b`.
+    rdiv: CayleyMagma<S>,
+}
+
+/// A Latin square of order `N`, backed by an [`ArrayStorage`].
+pub type LatinSquareS<const N: usize> = LatinSquare<ArrayStorage<ArrayStorageE<usize, N>, N>>;
+
+/// A dynamically-sized Latin square, backed by a [`VecStorage`](crate::storage::VecStorage).
+pub type LatinSquareD = LatinSquare<VecStorageE<usize>>;
+
+/// An [isotopy](https://en.wikipedia.org/wiki/Quasigroup#Homotopy_and_isotopy)
+/// between two Latin squares `A` and `B`. These are three bijections such that
+/// `B[rows[i]][columns[j]] = symbols[A[i][j]]` for all `i, j`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Isotopy {
+    /// The permutation of the rows.
+    pub rows: PermutationD,
+
+    /// The permutation of the columns.
+    pub columns: PermutationD,
+
+    /// The permutation of the symbols.
+    pub symbols: PermutationD,
+}
+
+impl Isotopy {
+    /// Returns the identity isotopy on squares of a given order.
+    pub fn identity(order: usize) -> Self {
+        let id = PermutationD::identity(Size::from_usize(order));
+        Self {
+            rows: id.clone(),
+            columns: id.clone(),
+            symbols: id,
+        }
+    }
+}
+
+impl<S: Storage<Inner = usize>> LatinSquare<S> {
+    /// Returns the order of the square.
+    pub fn order(&self) -> usize {
+        self.mul.order()
+    }
+
+    /// Returns the square as the table of a magma.
+    pub fn as_magma(&self) -> &CayleyMagma<S> {
+        &self.mul
+    }
+
+    /// Returns the table of the square as a magma.
+    pub fn into_magma(self) -> CayleyMagma<S> {
+        self.mul
+    }
+
+    /// Returns the entry `a · b` of the square.
+    ///
+    /// # Panics
+    /// This method will panic if either index is out of bounds.
+    pub fn op(&self, a: usize, b: usize) -> usize {
+        self.mul.op(a, b)
+    }
+
+    /// Returns the left division `a \ b`, the unique `x` with `a · x = b`.
+    ///
+    /// # Panics
+    /// This method will panic if either index is out of bounds.
+    pub fn left_div(&self, a: usize, b: usize) -> usize {
+        self.ldiv.op(a, b)
+    }
+
+    /// Returns the right division `b / a`, the unique `y` with `y · a = b`.
+    ///
+    /// # Panics
+    /// This method will panic if either index is out of bounds.
+    pub fn right_div(&self, b: usize, a: usize) -> usize {
+        self.rdiv.op(b, a)
+    }
+
+    /// Returns the table for left division.
+    pub fn left_div_table(&self) -> &CayleyMagma<S> {
+        &self.ldiv
+    }
+
+    /// Returns the table for right division.
+    pub fn right_div_table(&self) -> &CayleyMagma<S> {
+        &self.rdiv
+    }
+
+    /// Returns an element of the square, which may be operated on through the
+    /// [`BinOp`] trait.
+    ///
+    /// # Panics
+    /// This method will panic if the element is out of bounds.
+    pub fn element(&self, value: usize) -> LatinElement<'_, S> {
+        assert!(value < self.order(), "index out of bounds");
+        LatinElement {
+            square: self,
+            value,
+        }
+    }
+
+    /// Returns an iterator over the elements of the square.
+    pub fn elements(&self) -> impl Iterator<Item = LatinElement<'_, S>> {
+        (0..self.order()).map(move |value| LatinElement {
+            square: self,
+            value,
+        })
+    }
+
+    /// Returns an isomorphism between the quasigroups of two squares, if any.
+    /// See [`are_isomorphic`].
+    pub fn isomorphism<T: Storage<Inner = usize>>(
+        &self,
+        other: &LatinSquare<T>,
+    ) -> Option<PermutationD> {
+        are_isomorphic(&self.mul, &other.mul)
+    }
+
+    /// Returns the loop obtained from the square by relabelling its rows and
+    /// columns by the entries in column `c` and row `r`, respectively. Its
+    /// identity is the entry `r · c`.
+    fn principal_loop(&self, r: usize, c: usize) -> CayleyMagmaD {
+        CayleyMagma::from_fn(self.order(), |x, y| {
+            self.op(self.right_div(x, c), self.left_div(r, y))
+        })
+    }
+
+    /// Returns an isotopy from this square to another, if any.
+    ///
+    /// Every Latin square is isotopic to the loops we get by relabelling it
+    /// along some row and column. Two squares are isotopic exactly when some
+    /// such loop for one is isomorphic to a fixed such loop for the other, so
+    /// this performs `O(n²)` isomorphism tests.
+    pub fn isotopy<T: Storage<Inner = usize>>(&self, other: &LatinSquare<T>) -> Option<Isotopy> {
+        let n = self.order();
+        if n != other.order() {
+            return None;
+        } else if n == 0 {
+            return Some(Isotopy::identity(0));
+        }
+
+        let lhs = self.principal_loop(0, 0);
+        for r in 0..n {
+            for c in 0..n {
+                if let Some(p) = are_isomorphic(&lhs, &other.principal_loop(r, c)) {
+                    return Some(Isotopy {
+                        rows: (0..n)
+                            .map(|i| other.right_div(p[self.op(i, 0)], c))
+                            .collect(),
+                        columns: (0..n)
+                            .map(|j| other.left_div(r, p[self.op(0, j)]))
+                            .collect(),
+                        symbols: p,
+                    });
+                }
+            }
+        }
+
+        None
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> LatinSquare<S> {
+    /// Initializes a Latin square from the table of a magma. Returns `None` if
+    /// the magma isn't a quasigroup.
+    pub fn new(mul: CayleyMagma<S>) -> Option<Self> {
+        if !mul.is_quasigroup() {
+            return None;
+        }
+
+        let n = mul.order();
+        let mut ldiv = vec![0; n * n];
+        let mut rdiv = vec![0; n * n];
+        for a in 0..n {
+            for x in 0..n {
+                ldiv[a * n + mul.op(a, x)] = x;
+                rdiv[mul.op(x, a) * n + a] = x;
+            }
+        }
+
+        Some(Self {
+            mul,
+            ldiv: CayleyMagma::from_fn(n, |a, b| ldiv[a * n + b]),
+            rdiv: CayleyMagma::from_fn(n, |a, b| rdiv[a * n + b]),
+        })
+    }
+
+    /// Returns the cyclic Latin square of a given order, the table of addition
+    /// modulo `n`.
+    pub fn cyclic(order: usize) -> Self {
+        Self::new(CayleyMagma::from_fn(order, |a, b| (a + b) % order)).unwrap()
+    }
+
+    /// Returns a random Latin square of a given order.
+    ///
+    /// We run the Markov chain of Jacobson and Matthews (1996) for `n³` steps
+    /// from the cyclic square. Its stationary distribution is uniform over all
+    /// Latin squares, though the exact mixing time is unknown.
+    pub fn random(order: usize, rng: &mut Rng) -> Self {
+        let n = order;
+        let idx = |r: usize, c: usize, s: usize| (r * n + c) * n + s;
+
+        // The incidence cube of the square, which may temporarily have a single
+        // improper cell with a -1.
+        let mut cube = vec![0i8; n * n * n];
+        for r in 0..n {
+            for c in 0..n {
+                cube[idx(r, c, (r + c) % n)] = 1;
+            }
+        }
+
+        /// Picks one of the cells along a line which are set to 1.
+        fn pick<F: Fn(usize) -> usize>(cube: &[i8], n: usize, rng: &mut Rng, f: F) -> usize {
+            let ones: Vec<_> = (0..n).filter(|&i| cube[f(i)] == 1).collect();
+            ones[rng.index(ones.len())]
+        }
+
+        let mut improper = None;
+        let mut steps = 0;
+        while n > 1 && (steps < n * n * n || improper.is_some()) {
+            steps += 1;
+
+            let (r, c, s) = improper.unwrap_or_else(|| loop {
+                let (r, c, s) = (rng.index(n), rng.index(n), rng.index(n));
+                if cube[idx(r, c, s)] == 0 {
+                    break (r, c, s);
+                }
+            });
+
+            let r1 = pick(&cube, n, rng, |i| idx(i, c, s));
+            let c1 = pick(&cube, n, rng, |j| idx(r, j, s));
+            let s1 = pick(&cube, n, rng, |k| idx(r, c, k));
+
+            for &(r, c, s) in &[(r, c, s), (r, c1, s1), (r1, c, s1), (r1, c1, s)] {
+                cube[idx(r, c, s)] += 1;
+            }
+            for &(r, c, s) in &[(r, c, s1), (r, c1, s), (r1, c, s), (r1, c1, s1)] {
+                cube[idx(r, c, s)] -= 1;
+            }
+
+            improper = (cube[idx(r1, c1, s1)] == -1).then_some((r1, c1, s1));
+        }
+
+        let mul =
+            CayleyMagma::from_fn(n, |r, c| (0..n).find(|&s| cube[idx(r, c, s)] == 1).unwrap());
+        Self::new(mul).unwrap()
+    }
+
+    /// Applies an isotopy to the square.
+    ///
+    /// # Panics
+    /// This method will panic if the isotopy has a different order.
+    pub fn isotope(&self, isotopy: &Isotopy) -> Self {
+        let n = self.order();
+        assert!(
+            isotopy.rows.len() == n && isotopy.columns.len() == n && isotopy.symbols.len() == n,
+            "size mismatch"
+        );
+
+        let (rows, columns) = (isotopy.rows.inv(), isotopy.columns.inv());
+        let mul = CayleyMagma::from_fn(n, |x, y| isotopy.symbols[self.op(rows[x], columns[y])]);
+        Self::new(mul).unwrap()
+    }
+}
+
+/// An element of a [`LatinSquare`].
+#[derive(Debug)]
+pub struct LatinElement<'a, S: Storage<Inner = usize>> {
+    /// The square the element belongs to.
+    square: &'a LatinSquare<S>,
+
+    /// The index of the element.
+    value: usize,
+}
+
+impl<S: Storage<Inner = usize>> Clone for LatinElement<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Storage<Inner = usize>> Copy for LatinElement<'_, S> {}
+
+/// Elements are equal when they're the same element of squares with the same
+/// table.
+impl<S: Storage<Inner = usize>> PartialEq for LatinElement<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.same_square(other)
+    }
+}
+
+impl<S: Storage<Inner = usize>> Eq for LatinElement<'_, S> {}
+
+impl<'a, S: Storage<Inner = usize>> LatinElement<'a, S> {
+    /// Returns the index of the element.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Returns the square the element belongs to.
+    pub fn square(&self) -> &'a LatinSquare<S> {
+        self.square
+    }
+
+    /// Returns whether two elements belong to squares with the same table.
+    /// The division tables are determined by it, so they aren't compared.
+    ///
+    /// Elements of a single square are checked in constant time, while
+    /// elements of distinct copies of a square take O(n²) time, as in
+    /// [`CayleyMagma::same_table`].
+    fn same_square(&self, other: &Self) -> bool {
+        ptr::eq(self.square, other.square) || self.square.mul.same_table(&other.square.mul)
+    }
+
+    /// Looks up the entry of one of the tables of the square at the row and
+    /// column of two elements.
+    ///
+    /// # Panics
+    ///
+    /// This method will panic if the elements belong to squares with
+    /// different tables.
+    fn lookup(&self, rhs: &Self, table: &CayleyMagma<S>) -> Self {
+        assert!(
+            self.same_square(rhs),
+            "the elements belong to different squares"
+        );
+
+        Self {
+            square: self.square,
+            value: table.op(self.value, rhs.value),
+        }
+    }
+}
+
+/// Implements an operation on the elements of a Latin square, which looks up
+/// one of its tables.
+macro_rules! impl_latin_op {
+    ($op:ty, $table:ident) => {
+        impl<S: Storage<Inner = usize>> BinOp<$op> for LatinElement<'_, S> {
+            type Output = Self;
+            type Err = ();
+
+            /// # Panics
+            ///
+            /// This method will panic if the elements belong to squares with
+            /// different tables.
+            fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+                Ok(self.lookup(rhs, &self.square.$table))
+            }
+        }
+
+        impl<S: Storage<Inner = usize>> BinOpAssign<$op> for LatinElement<'_, S> {
+            type Err = ();
+
+            fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+                *self = BinOp::<$op>::bin_op(self, rhs)?;
+                Ok(())
+            }
+
+            fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+                *rhs = BinOp::<$op>::bin_op(self, rhs)?;
+                Ok(())
+            }
+        }
+
+        /// Operating on elements of squares with different tables isn't a
+        /// "normal" circumstance.
+        impl<S: Storage<Inner = usize>> Magma<$op> for LatinElement<'_, S> {}
+    };
+}
+
+impl_latin_op!(Mul, mul);
+impl_latin_op!(Div, rdiv);
+impl_latin_op!(LDiv, ldiv);
+
+/// Declares the elements of a Latin square as a quasigroup under an operation,
+/// with its left and right divisions.
+macro_rules! impl_latin_quasi {
+    ($($op:ty: $linv:ty, $rinv:ty);*) => {
+        $(
+            impl<S: Storage<Inner = usize>> LeftQuasigroup<$op> for LatinElement<'_, S> {
+                type LInv = $linv;
+            }
+
+            impl<S: Storage<Inner = usize>> RightQuasigroup<$op> for LatinElement<'_, S> {
+                type RInv = $rinv;
+            }
+        )*
+    };
+}
+
+// Each of these is a parastrophe of the square: the six ways to solve
+// `a · b = c` for one of its variables, in terms of the others.
+impl_latin_quasi!(
+    Mul: LDiv, Div;
+    Div: Bws<LDiv>, Mul;
+    LDiv: Mul, Bws<Div>;
+    Bws<Mul>: Bws<Div>, Bws<LDiv>;
+    Bws<Div>: Bws<Mul>, LDiv;
+    Bws<LDiv>: Div, Bws<Mul>
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cayley::{CayleyMagmaS, LatinSquareS};
+
+    #[test]
+    fn new() {
+        let z3 = CayleyMagmaS::<3>::from_fn(3, |a, b| (a + 2 * b) % 3);
+        let square = LatinSquareS::<3>::new(z3).unwrap();
+        assert_eq!(square.left_div(1, 0), 1);
+        assert_eq!(square.right_div(0, 1), 1);
+
+        // Copies of a square share their elements.
+        let copy = square;
+        let (a, b) = (square.element(1), copy.element(2));
+        assert_eq!(BinOp::<Mul>::bin_op(&a, &b), Ok(square.element(2)));
+        assert_eq!(BinOp::<LDiv>::bin_op(&a, &b), Ok(copy.element(2)));
+        let other = LatinSquareS::<3>::new(CayleyMagma::from_fn(3, |a, b| (a + b) % 3)).unwrap();
+        assert_ne!(a, other.element(1));
+        assert!(LatinSquareD::new(CayleyMagma::from_fn(3, |a, b| a * b % 3)).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_squares() {
+        let square = LatinSquareD::cyclic(3);
+        let other = LatinSquareD::new(CayleyMagma::from_fn(3, |a, b| (a + 2 * b) % 3)).unwrap();
+        BinOp::<Div>::bin_op(&square.element(1), &other.element(2)).ok();
+    }
+
+    #[test]
+    fn quasigroup() {
+        /// Tests the divisions of a quasigroup under some operation.
+        macro_rules! test_quasi {
+            ($a:expr, $b:expr, $($op:ty),*) => {
+                $(
+                    assert!(<LatinElement<_> as LeftQuasigroup<$op>>::test_left_div($a, $b));
+                    assert!(<LatinElement<_> as RightQuasigroup<$op>>::test_right_div($a, $b));
+                )*
+            };
+        }
+
+        let square = LatinSquareD::random(7, &mut Rng::new(1));
+        assert!(square.as_magma().is_quasigroup());
+        for a in square.elements() {
+            for b in square.elements() {
+                test_quasi!(&a, &b, Mul, Div, LDiv, Bws<Mul>, Bws<Div>, Bws<LDiv>);
+            }
+        }
+    }
+
+    #[test]
+    fn isotopy() {
+        let mut rng = Rng::new(5);
+        let square = LatinSquareD::random(6, &mut rng);
+        let size = Size::from_usize(6);
+        let isotopy = Isotopy {
+            rows: PermutationD::random(size, &mut rng),
+            columns: PermutationD::random(size, &mut rng),
+            symbols: PermutationD::random(size, &mut rng),
+        };
+
+        let other = square.isotope(&isotopy);
+        let found = square.isotopy(&other).unwrap();
+        assert_eq!(square.isotope(&found), other);
+
+        // The cyclic group of order 4 and the Klein four-group aren't isotopic.
+        let klein = LatinSquareD::new(CayleyMagma::from_fn(4, |a, b| a ^ b)).unwrap();
+        assert!(LatinSquareD::cyclic(4).isotopy(&klein).is_none());
+        assert!(LatinSquareD::cyclic(4).isomorphism(&klein).is_none());
+    }
+}
@@ -0,0 +1,469 @@
+//! Implements finite magmas defined by their
+//! [Cayley tables](https://en.wikipedia.org/wiki/Cayley_table).
+
+mod isomorphism;
+mod latin;
+
+pub use isomorphism::*;
+pub use latin::*;
+
+use std::{fmt, ptr};
+
+use algebra::{BinOp, BinOpAssign, Magma};
+
+use crate::storage::{ArrayStorage, ArrayStorageE, OwnedStorage, Size, Storage, VecStorageE};
+
+/// A finite magma on the elements `0..n`, defined by its Cayley table. This is
+/// stored in row-major order as some storage of length `n²`, so that the entry
+/// at row `a` and column `b` is the product `a · b`.
+///
+/// Statically-sized tables can be built and validated at compile time using
+/// the [`cayley_table!`](crate::cayley_table) macro.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CayleyMagma<S: Storage<Inner = usize>> {
+    /// The entries of the table, in row-major order.
+    table: S,
+
+    /// The number of elements of the magma.
+    order: usize,
+}
+
+/// A magma of order `N`, backed by an [`ArrayStorage`].
+pub type CayleyMagmaS<const N: usize> = CayleyMagma<ArrayStorage<ArrayStorageE<usize, N>, N>>;
+
+/// A dynamically-sized magma, backed by a [`VecStorage`](crate::storage::VecStorage).
+pub type CayleyMagmaD = CayleyMagma<VecStorageE<usize>>;
+
+/// The properties of a finite magma, as detected by [`CayleyMagma::properties`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Properties {
+    /// Whether `(a · b) · c = a · (b · c)` for all `a, b, c`.
+    pub associative: bool,
+
+    /// Whether `a · b = b · a` for all `a, b`.
+    pub commutative: bool,
+
+    /// The two-sided identity, if any.
+    pub identity: Option<usize>,
+
+    /// Whether the table is a [Latin square](https://en.wikipedia.org/wiki/Latin_square),
+    /// meaning the magma is a quasigroup.
+    pub quasigroup: bool,
+
+    /// Whether the magma is a [Moufang loop](https://en.wikipedia.org/wiki/Moufang_loop).
+    pub moufang: bool,
+}
+
+impl Properties {
+    /// Returns whether the magma is a monoid.
+    pub fn is_monoid(&self) -> bool {
+        self.associative && self.identity.is_some()
+    }
+
+    /// Returns whether the magma is a loop.
+    pub fn is_loop(&self) -> bool {
+        self.quasigroup && self.identity.is_some()
+    }
+
+    /// Returns whether the magma is a group.
+    pub fn is_group(&self) -> bool {
+        self.associative && self.is_loop()
+    }
+}
+
+impl fmt::Display for Properties {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut props = Vec::new();
+        if self.associative {
+            props.push("associative".to_owned());
+        }
+        if self.commutative {
+            props.push("commutative".to_owned());
+        }
+        if let Some(e) = self.identity {
+            props.push(format!("identity {}", e));
+        }
+        if self.quasigroup {
+            props.push("quasigroup".to_owned());
+        }
+        if self.moufang {
+            props.push("Moufang".to_owned());
+        }
+
+        if props.is_empty() {
+            write!(f, "none")
+        } else {
+            write!(f, "{}", props.join(", "))
+        }
+    }
+}
+
+impl<S: Storage<Inner = usize>> CayleyMagma<S> {
+    /// Initializes a new magma from its table. Returns `None` if the length of
+    /// the storage isn't a perfect square `n²`, or if some entry isn't less
+    /// than `n`.
+    pub fn new(table: S) -> Option<Self> {
+        let len = table.len();
+        let order = (0..=len).find(|n| n * n >= len)?;
+
+        (order * order == len && table.iter().all(|&x| x < order)).then_some(Self { table, order })
+    }
+
+    /// Initializes a new magma from its table, without checking it.
+    ///
+    /// # Safety
+    /// The storage must have length `order²`, and all of its entries must be
+    /// less than `order`.
+    pub const unsafe fn new_unchecked(table: S, order: usize) -> Self {
+        Self { table, order }
+    }
+
+    /// Returns the number of elements of the magma.
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    /// Returns the underlying storage of the table.
+    pub fn into_storage(self) -> S {
+        self.table
+    }
+
+    /// Returns whether two magmas have the same table. Unlike [`PartialEq`],
+    /// this doesn't require the storage to be comparable.
+    ///
+    /// Magmas at the same address are equal and magmas of different orders
+    /// aren't, both in constant time. Otherwise, this compares the tables
+    /// entry by entry, which takes O(n²) time. Elements of a single magma
+    /// only ever take the first path, so only mixing copies of a table
+    /// pays this cost.
+    pub(crate) fn same_table(&self, other: &Self) -> bool {
+        ptr::eq(self, other)
+            || (self.order == other.order
+                && (0..self.order * self.order).all(|i| self.table[i] == other.table[i]))
+    }
+
+    /// Returns the product `a · b`.
+    ///
+    /// # Panics
+    /// This method will panic if either element is out of bounds.
+    pub fn op(&self, a: usize, b: usize) -> usize {
+        assert!(a < self.order && b < self.order, "index out of bounds");
+        self.table[a * self.order + b]
+    }
+
+    /// Returns an element of the magma, which may be operated on through the
+    /// [`BinOp`] trait.
+    ///
+    /// # Panics
+    /// This method will panic if the element is out of bounds.
+    pub fn element(&self, value: usize) -> CayleyElement<'_, S> {
+        assert!(value < self.order, "index out of bounds");
+        CayleyElement { magma: self, value }
+    }
+
+    /// Returns an iterator over the elements of the magma.
+    pub fn elements(&self) -> impl Iterator<Item = CayleyElement<'_, S>> {
+        (0..self.order).map(move |value| CayleyElement { magma: self, value })
+    }
+
+    /// Returns whether the magma is associative. This takes `O(n³)` time.
+    pub fn is_associative(&self) -> bool {
+        let n = self.order;
+        (0..n).all(|a| {
+            (0..n).all(|b| {
+                let ab = self.op(a, b);
+                (0..n).all(|c| self.op(ab, c) == self.op(a, self.op(b, c)))
+            })
+        })
+    }
+
+    /// Returns whether the magma is commutative.
+    pub fn is_commutative(&self) -> bool {
+        let n = self.order;
+        (0..n).all(|a| (0..a).all(|b| self.op(a, b) == self.op(b, a)))
+    }
+
+    /// Returns the two-sided identity of the magma, if any.
+    pub fn identity(&self) -> Option<usize> {
+        let n = self.order;
+        (0..n).find(|&e| (0..n).all(|a| self.op(e, a) == a && self.op(a, e) == a))
+    }
+
+    /// Returns whether the table is a Latin square, meaning that every element
+    /// appears exactly once in every row and every column. These are exactly
+    /// the tables of quasigroups.
+    pub fn is_quasigroup(&self) -> bool {
+        let n = self.order;
+        let mut seen = vec![false; n];
+        let mut check = |f: &dyn Fn(usize) -> usize| {
+            seen.iter_mut().for_each(|s| *s = false);
+            (0..n).all(|j| !std::mem::replace(&mut seen[f(j)], true))
+        };
+
+        (0..n).all(|i| check(&|j| self.op(i, j)) && check(&|j| self.op(j, i)))
+    }
+
+    /// Returns whether the magma is a Moufang loop. Besides being a loop, this
+    /// means `z · (x · (z · y)) = ((z · x) · z) · y` for all `x, y, z`. This
+    /// takes `O(n³)` time.
+    pub fn is_moufang(&self) -> bool {
+        let n = self.order;
+        self.identity().is_some()
+            && self.is_quasigroup()
+            && (0..n).all(|z| {
+                (0..n).all(|x| {
+                    let zxz = self.op(self.op(z, x), z);
+                    (0..n).all(|y| self.op(z, self.op(x, self.op(z, y))) == self.op(zxz, y))
+                })
+            })
+    }
+
+    /// Returns the two-sided inverse of every element, if the magma is a loop
+    /// and these exist.
+    fn inverses(&self) -> Option<Vec<usize>> {
+        let e = self.identity()?;
+        if !self.is_quasigroup() {
+            return None;
+        }
+
+        let n = self.order;
+        (0..n)
+            .map(|a| (0..n).find(|&x| self.op(a, x) == e && self.op(x, a) == e))
+            .collect()
+    }
+
+    /// Returns whether the magma is an [inverse property loop](https://en.wikipedia.org/wiki/Quasigroup#Inverse_properties).
+    /// Besides being a loop, this means that every element has a two-sided
+    /// inverse, with `a⁻¹ · (a · b) = b = (b · a) · a⁻¹` for all `a, b`. Every
+    /// Moufang loop has this property.
+    pub fn has_inverse_property(&self) -> bool {
+        let n = self.order;
+        self.inverses().is_some_and(|inv| {
+            (0..n).all(|a| {
+                (0..n).all(|b| {
+                    self.op(inv[a], self.op(a, b)) == b && self.op(self.op(b, a), inv[a]) == b
+                })
+            })
+        })
+    }
+
+    /// Returns whether the magma is an inverse property loop with the
+    /// automorphic inverse property, meaning `(a · b)⁻¹ = a⁻¹ · b⁻¹` for all
+    /// `a, b`.
+    pub fn has_automorphic_inverse_property(&self) -> bool {
+        let n = self.order;
+        self.has_inverse_property()
+            && self.inverses().is_some_and(|inv| {
+                (0..n).all(|a| (0..n).all(|b| inv[self.op(a, b)] == self.op(inv[a], inv[b])))
+            })
+    }
+
+    /// Detects which of the common properties of magmas hold.
+    pub fn properties(&self) -> Properties {
+        Properties {
+            associative: self.is_associative(),
+            commutative: self.is_commutative(),
+            identity: self.identity(),
+            quasigroup: self.is_quasigroup(),
+            moufang: self.is_moufang(),
+        }
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> CayleyMagma<S> {
+    /// Initializes a magma of a given order, whose product `a · b` is
+    /// `f(a, b)`.
+    ///
+    /// # Panics
+    /// This method will panic if the square of the order is incompatible with
+    /// the size of the storage, or if some product is out of bounds.
+    pub fn from_fn<F: FnMut(usize, usize) -> usize>(order: usize, mut f: F) -> Self {
+        Size::<S>::from_usize(order * order);
+
+        let table = (0..order * order)
+            .map(|k| {
+                let x = f(k / order, k % order);
+                assert!(x < order, "product out of bounds");
+                x
+            })
+            .collect();
+
+        Self { table, order }
+    }
+}
+
+/// An element of a [`CayleyMagma`].
+#[derive(Debug)]
+pub struct CayleyElement<'a, S: Storage<Inner = usize>> {
+    /// The magma the element belongs to.
+    magma: &'a CayleyMagma<S>,
+
+    /// The index of the element.
+    value: usize,
+}
+
+impl<S: Storage<Inner = usize>> Clone for CayleyElement<'_, S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Storage<Inner = usize>> Copy for CayleyElement<'_, S> {}
+
+/// Elements are equal when they're the same element of magmas with the same
+/// table.
+impl<S: Storage<Inner = usize>> PartialEq for CayleyElement<'_, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.magma.same_table(other.magma)
+    }
+}
+
+impl<S: Storage<Inner = usize>> Eq for CayleyElement<'_, S> {}
+
+impl<'a, S: Storage<Inner = usize>> CayleyElement<'a, S> {
+    /// Returns the index of the element.
+    pub fn value(&self) -> usize {
+        self.value
+    }
+
+    /// Returns the magma the element belongs to.
+    pub fn magma(&self) -> &'a CayleyMagma<S> {
+        self.magma
+    }
+}
+
+impl<S: Storage<Inner = usize>> BinOp<algebra::Mul> for CayleyElement<'_, S> {
+    type Output = Self;
+    type Err = ();
+
+    /// # Panics
+    ///
+    /// This method will panic if the elements belong to magmas with different
+    /// tables.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        assert!(
+            self.magma.same_table(rhs.magma),
+            "the elements belong to different magmas"
+        );
+
+        Ok(Self {
+            magma: self.magma,
+            value: self.magma.op(self.value, rhs.value),
+        })
+    }
+}
+
+impl<S: Storage<Inner = usize>> BinOpAssign<algebra::Mul> for CayleyElement<'_, S> {
+    type Err = ();
+
+    fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+        *self = BinOp::<algebra::Mul>::bin_op(self, rhs)?;
+        Ok(())
+    }
+
+    fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+        *rhs = BinOp::<algebra::Mul>::bin_op(self, rhs)?;
+        Ok(())
+    }
+}
+
+impl<S: Storage<Inner = usize>> Magma<algebra::Mul> for CayleyElement<'_, S> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cayley_table, permutation::PermGroupS};
+
+    /// The Klein four-group.
+    const KLEIN: CayleyMagmaS<4> =
+        cayley_table![[0, 1, 2, 3], [1, 0, 3, 2], [2, 3, 0, 1], [3, 2, 1, 0],];
+
+    #[test]
+    fn properties() {
+        let props = KLEIN.properties();
+        assert!(props.is_group() && props.commutative && props.moufang);
+        assert_eq!(
+            props.to_string(),
+            "associative, commutative, identity 0, quasigroup, Moufang"
+        );
+
+        // Copies of a magma share their elements.
+        let k = KLEIN;
+        let a = k.element(1);
+        let b = KLEIN.element(2);
+        assert_eq!(Magma::<algebra::Mul>::op(&a, &b), KLEIN.element(3));
+        assert_eq!(Magma::<algebra::Mul>::op(&a, &k.element(3)), b);
+
+        let z4: CayleyMagmaS<4> =
+            cayley_table![[0, 1, 2, 3], [1, 2, 3, 0], [2, 3, 0, 1], [3, 0, 1, 2],];
+        assert_ne!(a, z4.element(1));
+
+        // Subtraction modulo 3 is a quasigroup, but nothing more.
+        let sub = CayleyMagmaD::from_fn(3, |a, b| (a + 3 - b) % 3);
+        assert_eq!(
+            sub.properties(),
+            Properties {
+                quasigroup: true,
+                ..Default::default()
+            }
+        );
+
+        // The smallest loop that isn't a group.
+        let table = [
+            0, 1, 2, 3, 4, 1, 0, 3, 4, 2, 2, 4, 0, 1, 3, 3, 2, 4, 0, 1, 4, 3, 1, 2, 0,
+        ];
+        let l = CayleyMagmaD::new(table.iter().copied().collect()).unwrap();
+        let props = l.properties();
+        assert!(props.is_loop() && !props.associative && !props.moufang);
+        assert!(CayleyMagmaD::new([0, 1, 2].iter().copied().collect()).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn different_magmas() {
+        let z4: CayleyMagmaS<4> =
+            cayley_table![[0, 1, 2, 3], [1, 2, 3, 0], [2, 3, 0, 1], [3, 0, 1, 2],];
+        Magma::<algebra::Mul>::op(&KLEIN.element(1), &z4.element(2));
+    }
+
+    #[test]
+    fn moufang() {
+        // Chein's construction M(S₃, 2) gives the smallest non-associative
+        // Moufang loop, of order 12.
+        let s3: Vec<_> = PermGroupS::<3>::symmetric(Default::default())
+            .elements()
+            .collect();
+        let index = |p| s3.iter().position(|q| q == &p).unwrap();
+
+        let m = CayleyMagmaD::from_fn(12, |a, b| {
+            let (g, h) = (&s3[a % 6], &s3[b % 6]);
+            match (a / 6, b / 6) {
+                (0, 0) => index(g * h),
+                (0, _) => 6 + index(h * g),
+                (_, 0) => 6 + index(g * h.inv()),
+                _ => index(h.inv() * g),
+            }
+        });
+
+        let props = m.properties();
+        assert!(props.moufang && !props.associative && !props.commutative);
+        assert!(m.has_inverse_property() && !m.has_automorphic_inverse_property());
+    }
+
+    #[test]
+    fn commutative_moufang() {
+        // The smallest commutative Moufang loop that isn't a group, on ℤ₃⁴.
+        let digits = |a: usize| [a % 3, a / 3 % 3, a / 9 % 3, a / 27];
+        let m = CayleyMagmaD::from_fn(81, |a, b| {
+            let (x, y) = (digits(a), digits(b));
+            let twist = (x[2] + 3 - y[2]) * (x[0] * y[1] + 6 - x[1] * y[0]);
+            let z: Vec<_> = (0..4).map(|i| (x[i] + y[i]) % 3).collect();
+            z[0] + 3 * z[1] + 9 * z[2] + 27 * ((z[3] + twist) % 3)
+        });
+
+        let props = m.properties();
+        assert!(props.moufang && props.commutative && !props.associative);
+        assert!(m.has_automorphic_inverse_property());
+        assert!(KLEIN.has_automorphic_inverse_property());
+    }
+}
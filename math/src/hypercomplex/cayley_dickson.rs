@@ -0,0 +1,299 @@
+//! Implements the [Cayley–Dickson construction](https://en.wikipedia.org/wiki/Cayley%E2%80%93Dickson_construction)
+//! over an arbitrary [`StarAlgebra`].
+
+use algebra::{
+    Add, Associative, Automorphism, BinOp, BinOpAssign, Bws, Commutative, Conj, DivisionRing,
+    Endomorphism, Flexible, Group, Injective, Involution, LeftAlternative, LeftQuasigroup,
+    LeftUnital, Magma, Mul, Neg, PowAssociative, Rec, RightAlternative, RightQuasigroup,
+    RightUnital, Ring, Sub, Surjective, UnOp, UnOpAssign,
+};
+
+use super::{Octonion, StarAlgebra};
+
+/// The algebra obtained by doubling a [`StarAlgebra`] through the Cayley–Dickson
+/// construction. A pair `(a, b)` represents `a + bℓ` for a new imaginary unit
+/// `ℓ`, with
+///
+/// - `(a + bℓ)(c + dℓ) = (ac - d*b) + (da + bc*)ℓ`,
+/// - `(a + bℓ)* = a* - bℓ`.
+///
+/// Each doubling loses some structure, and this type implements whichever
+/// traits survive it:
+///
+/// - The result is commutative when `T` is its own ring of scalars.
+/// - The result is associative when `T` is commutative and associative.
+/// - The result is [alternative](algebra::Alternative) when `T` is associative.
+/// - The result is flexible and power associative when `T` is flexible.
+///
+/// Starting from the reals, this gives the [complex numbers](super::Complex),
+/// the [quaternions](super::Quaternion), the [octonions](Octonion) and the
+/// [sedenions](Sedenion) in turn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CayleyDickson<T> {
+    /// The part in the original algebra.
+    pub a: T,
+
+    /// The coefficient of `ℓ`.
+    pub b: T,
+}
+
+/// The [sedenions](https://en.wikipedia.org/wiki/Sedenion) over a ring, built
+/// by doubling the [octonions](Octonion). These are flexible and power
+/// associative, but not alternative, and they have zero divisors.
+pub type Sedenion<T> = CayleyDickson<Octonion<T>>;
+
+impl<T> CayleyDickson<T> {
+    /// Initializes the element `a + bℓ`.
+    pub const fn new(a: T, b: T) -> Self {
+        Self { a, b }
+    }
+}
+
+impl<T: StarAlgebra> CayleyDickson<T> {
+    /// Returns the conjugate `a* - bℓ`.
+    pub fn conj(&self) -> Self {
+        Self::new(self.a.involution_op(), Group::<Add>::inv(&self.b))
+    }
+}
+
+impl<T: StarAlgebra> CayleyDickson<T>
+where
+    T::Scalar: DivisionRing,
+{
+    /// Returns the multiplicative inverse `x* / |x|²`, or `None` if the norm is
+    /// zero.
+    pub fn rec(&self) -> Option<Self> {
+        self.norm().rec().map(|r| self.conj().scale(&r))
+    }
+}
+
+impl_additive!(CayleyDickson: a, b; [StarAlgebra]);
+impl_assign!(CayleyDickson: Mul; [StarAlgebra]);
+impl_conj!(CayleyDickson; [StarAlgebra]);
+
+impl<T: StarAlgebra> BinOp<Mul> for CayleyDickson<T> {
+    type Output = Self;
+    type Err = ();
+
+    /// Evaluates `(a + bℓ)(c + dℓ) = (ac - d*b) + (da + bc*)ℓ`.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        let (a, b, c, d) = (&self.a, &self.b, &rhs.a, &rhs.b);
+        let re = Magma::<Add>::op(
+            &Magma::<Mul>::op(a, c),
+            &Group::<Add>::inv(&Magma::<Mul>::op(&d.involution_op(), b)),
+        );
+        let im = Magma::<Add>::op(
+            &Magma::<Mul>::op(d, a),
+            &Magma::<Mul>::op(b, &c.involution_op()),
+        );
+
+        Ok(Self::new(re, im))
+    }
+}
+
+impl<T: StarAlgebra> Magma<Mul> for CayleyDickson<T> {}
+
+impl<T: StarAlgebra> LeftUnital<Mul> for CayleyDickson<T> {
+    fn left_id() -> Self {
+        Self::from_scalar(T::Scalar::one())
+    }
+}
+
+impl<T: StarAlgebra> RightUnital<Mul> for CayleyDickson<T> {
+    fn right_id() -> Self {
+        Self::from_scalar(T::Scalar::one())
+    }
+}
+
+impl<T: StarAlgebra> StarAlgebra for CayleyDickson<T> {
+    type Scalar = T::Scalar;
+
+    fn from_scalar(k: T::Scalar) -> Self {
+        Self::new(T::from_scalar(k), LeftUnital::<Add>::left_id())
+    }
+
+    fn scale(&self, k: &T::Scalar) -> Self {
+        Self::new(self.a.scale(k), self.b.scale(k))
+    }
+
+    fn norm(&self) -> T::Scalar {
+        self.a.norm().add(&self.b.norm())
+    }
+}
+
+impl<T: StarAlgebra> UnOp<Rec> for CayleyDickson<T>
+where
+    T::Scalar: DivisionRing,
+{
+    type Output = Self;
+    type Err = ();
+
+    /// Evaluates `x* / |x|²`, or returns an error if the norm is zero.
+    fn un_op(&self) -> Result<Self, ()> {
+        self.rec().ok_or(())
+    }
+}
+
+impl<T: StarAlgebra> UnOpAssign<Rec> for CayleyDickson<T>
+where
+    T::Scalar: DivisionRing,
+{
+    type Err = ();
+
+    fn un_op_assign(&mut self) -> Result<(), ()> {
+        *self = UnOp::<Rec>::un_op(self)?;
+        Ok(())
+    }
+}
+
+impl<T: StarAlgebra + Flexible<Mul>> PowAssociative<Mul> for CayleyDickson<T> {}
+impl<T: StarAlgebra + Flexible<Mul>> Flexible<Mul> for CayleyDickson<T> {}
+impl<T: StarAlgebra + Associative<Mul>> LeftAlternative<Mul> for CayleyDickson<T> {}
+impl<T: StarAlgebra + Associative<Mul>> RightAlternative<Mul> for CayleyDickson<T> {}
+
+impl<T: StarAlgebra<Scalar = T>> Commutative<Mul> for CayleyDickson<T> {}
+
+impl<T: StarAlgebra + Flexible<Mul> + Associative<Mul> + Commutative<Mul>> Associative<Mul>
+    for CayleyDickson<T>
+{
+}
+
+impl<T: StarAlgebra + Flexible<Mul> + Associative<Mul> + Commutative<Mul>> Ring
+    for CayleyDickson<T>
+{
+}
+
+impl<T: StarAlgebra + Flexible<Mul> + Associative<Mul> + Commutative<Mul>> DivisionRing
+    for CayleyDickson<T>
+where
+    T::Scalar: DivisionRing,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{hypercomplex::Quaternion, R};
+    use algebra::Field;
+
+    /// Asserts that a type is a field.
+    fn assert_field<T: Field>() {}
+
+    /// Builds an element of an iterated Cayley–Dickson algebra over `i64` from
+    /// its coefficients.
+    trait FromCoefs: Sized {
+        /// The number of coefficients.
+        const DIM: usize;
+
+        /// Builds an element from its coefficients.
+        fn from_coefs(c: &[i64]) -> Self;
+    }
+
+    impl FromCoefs for i64 {
+        const DIM: usize = 1;
+
+        fn from_coefs(c: &[i64]) -> Self {
+            c[0]
+        }
+    }
+
+    impl FromCoefs for Quaternion<i64> {
+        const DIM: usize = 4;
+
+        fn from_coefs(c: &[i64]) -> Self {
+            Quaternion::new(c[0], c[1], c[2], c[3])
+        }
+    }
+
+    impl<T: FromCoefs> FromCoefs for CayleyDickson<T> {
+        const DIM: usize = 2 * T::DIM;
+
+        fn from_coefs(c: &[i64]) -> Self {
+            let (a, b) = c.split_at(T::DIM);
+            Self::new(T::from_coefs(a), T::from_coefs(b))
+        }
+    }
+
+    /// Returns the basis elements of an algebra, followed by a few others.
+    fn samples<T: FromCoefs>() -> Vec<T> {
+        let mut res: Vec<_> = (0..T::DIM)
+            .map(|i| T::from_coefs(&(0..T::DIM).map(|j| (i == j) as i64).collect::<Vec<_>>()))
+            .collect();
+
+        for seed in [3, 7] {
+            let c: Vec<_> = (0..T::DIM as i64).map(|i| (i * seed) % 5 - 2).collect();
+            res.push(T::from_coefs(&c));
+        }
+
+        res
+    }
+
+    #[test]
+    fn complex() {
+        assert_field::<CayleyDickson<R<f64>>>();
+
+        let i = CayleyDickson::new(0, 1);
+        assert_eq!(i.mul(&i), CayleyDickson::new(-1, 0));
+
+        let z = CayleyDickson::new(R(3.0), R(4.0));
+        assert_eq!(z.norm(), R(25.0));
+        assert_eq!(
+            z.div(&z.conj()),
+            Some(CayleyDickson::new(R(-0.28), R(0.96)))
+        );
+        assert!(z.test_rec());
+    }
+
+    #[test]
+    fn quaternion() {
+        type H = CayleyDickson<CayleyDickson<i64>>;
+        let to_quaternion = |x: &H| Quaternion::new(x.a.a, x.a.b, x.b.a, x.b.b);
+
+        let samples = samples::<H>();
+        let mut commutative = true;
+        for x in &samples {
+            for y in &samples {
+                assert_eq!(
+                    to_quaternion(&x.mul(y)),
+                    to_quaternion(x).mul(&to_quaternion(y))
+                );
+                commutative &= x.mul(y) == y.mul(x);
+            }
+        }
+
+        assert!(!commutative);
+    }
+
+    #[test]
+    fn sedenion() {
+        let samples = samples::<Sedenion<i64>>();
+        let mul = Magma::<Mul>::op;
+        let mut alternative = true;
+
+        for x in &samples {
+            assert!(PowAssociative::<Mul>::test_pow_assoc(x) && x.test_involution());
+            for y in &samples {
+                assert!(Sedenion::test_flexible(x, y));
+                alternative &= mul(&mul(x, x), y) == mul(x, &mul(x, y));
+            }
+        }
+
+        assert!(!alternative);
+
+        // The sedenions have zero divisors of the form (eᵢ + eⱼ)(eₖ ± eₗ).
+        let basis = &samples[..16];
+        let pairs: Vec<_> = (0..16)
+            .flat_map(|i| (i + 1..16).map(move |j| (i, j)))
+            .collect();
+        let zero = LeftUnital::<Add>::left_id();
+
+        assert!(pairs.iter().any(|&(i, j)| {
+            let x = Magma::<Add>::op(&basis[i], &basis[j]);
+            pairs.iter().any(|&(k, l)| {
+                [Magma::<Add>::op, Magma::<Sub>::op]
+                    .iter()
+                    .any(|op| mul(&x, &op(&basis[k], &basis[l])) == zero)
+            })
+        }));
+    }
+}
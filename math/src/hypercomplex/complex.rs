@@ -0,0 +1,269 @@
+//! Implements the [complex numbers](https://en.wikipedia.org/wiki/Complex_number)
+//! over a commutative ring, including the
+//! [Gaussian integers](https://en.wikipedia.org/wiki/Gaussian_integer).
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use algebra::{EuclideanDomain, Ring};
+
+use super::{CayleyDickson, StarAlgebra};
+use crate::R;
+
+/// A complex number `a + bi` with coefficients in a commutative ring, where
+/// `i² = -1`. This is the first step of the [Cayley–Dickson construction](CayleyDickson),
+/// so that `a` is the real part and `b` is the imaginary part.
+///
+/// Complex numbers over a [`Field`](algebra::Field) form a field themselves.
+/// The Gaussian integers `Complex<i64>` form a [`EuclideanDomain`] instead.
+///
+/// ```
+/// use algebra::Ring;
+/// use math::hypercomplex::Complex;
+///
+/// let z: Complex<i64> = "3+4i".parse().unwrap();
+/// assert_eq!(z.mul(&z).to_string(), "-7+24i");
+/// ```
+pub type Complex<T> = CayleyDickson<T>;
+
+impl<T: Ring + StarAlgebra<Scalar = T>> Complex<T> {
+    /// Returns the imaginary unit `i`.
+    pub fn i() -> Self {
+        Self::new(T::zero(), T::one())
+    }
+}
+
+impl Complex<R<f64>> {
+    /// Initializes the complex number with a given modulus and argument.
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        let (sin, cos) = theta.sin_cos();
+        Self::new(R(r * cos), R(r * sin))
+    }
+
+    /// Returns the modulus `|z|`.
+    pub fn abs(&self) -> f64 {
+        self.a.0.hypot(self.b.0)
+    }
+
+    /// Returns the argument, in the range `(-π, π]`.
+    pub fn arg(&self) -> f64 {
+        self.b.0.atan2(self.a.0)
+    }
+
+    /// Returns the modulus and argument.
+    pub fn to_polar(&self) -> (f64, f64) {
+        (self.abs(), self.arg())
+    }
+
+    /// Returns the exponential `eᶻ`.
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.a.0.exp(), self.b.0)
+    }
+
+    /// Returns the principal branch of the natural logarithm, whose imaginary
+    /// part lies in `(-π, π]`, or `None` for zero.
+    pub fn ln(&self) -> Option<Self> {
+        let (r, theta) = self.to_polar();
+        (r != 0.0).then(|| Self::new(R(r.ln()), R(theta)))
+    }
+
+    /// Returns the principal square root, whose real part is non-negative.
+    pub fn sqrt(&self) -> Self {
+        let (a, b) = (self.a.0, self.b.0);
+        let r = self.abs();
+        let re = ((r + a) / 2.0).sqrt();
+        let im = ((r - a) / 2.0).sqrt();
+        Self::new(R(re), R(im.copysign(b)))
+    }
+}
+
+impl EuclideanDomain for Complex<i64> {
+    type Norm = u128;
+
+    /// Returns the norm `a² + b²`, which can't overflow in 128 bits.
+    fn euclid_norm(&self) -> u128 {
+        let (a, b) = (self.a.unsigned_abs() as u128, self.b.unsigned_abs() as u128);
+        a * a + b * b
+    }
+
+    /// Divides by rounding each coefficient of the exact quotient to the
+    /// nearest integer, so that the remainder has at most half the norm of
+    /// `rhs`.
+    ///
+    /// This computes in 128 bits, where none of the products can overflow as
+    /// long as no coefficient is `i64::MIN`.
+    fn div_rem(&self, rhs: &Self) -> Option<(Self, Self)> {
+        let (a, b) = (self.a as i128, self.b as i128);
+        let (c, d) = (rhs.a as i128, rhs.b as i128);
+        let norm = c * c + d * d;
+        if norm == 0 {
+            return None;
+        }
+
+        // The numerator of the exact quotient self / rhs = self rhs* / |rhs|²,
+        // whose coefficients are rounded half up.
+        let round = |x: i128| {
+            let (q, r) = (x.div_euclid(norm), x.rem_euclid(norm));
+            q + (r >= norm - r) as i128
+        };
+        let (qa, qb) = (round(a * c + b * d), round(b * c - a * d));
+        let (ra, rb) = (a - (qa * c - qb * d), b - (qa * d + qb * c));
+        Some((
+            Self::new(qa as i64, qb as i64),
+            Self::new(ra as i64, rb as i64),
+        ))
+    }
+}
+
+impl<T: StarAlgebra<Scalar = T> + Display> Display for Complex<T> {
+    /// Writes the complex number as `a+bi` or `a-bi`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let im = self.b.to_string();
+        match im.strip_prefix('-') {
+            Some(abs) => write!(f, "{}-{}i", self.a, abs),
+            None => write!(f, "{}+{}i", self.a, im),
+        }
+    }
+}
+
+impl<T: Ring + StarAlgebra<Scalar = T> + FromStr> FromStr for Complex<T> {
+    type Err = ();
+
+    /// Parses a complex number such as `3+4i`, `-2.5`, `4i` or `1-i`.
+    /// Whitespace is ignored.
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let parse = |x: &str| x.parse().map_err(|_| ());
+
+        let Some(s) = s.strip_suffix('i') else {
+            return Ok(Self::new(parse(&s)?, T::zero()));
+        };
+
+        // The imaginary part starts at the last sign, unless it's the sign of
+        // an exponent.
+        let bytes = s.as_bytes();
+        let split = (1..s.len())
+            .rev()
+            .find(|&k| matches!(bytes[k], b'+' | b'-') && !matches!(bytes[k - 1], b'e' | b'E'))
+            .unwrap_or(0);
+
+        let (re, im) = s.split_at(split);
+        let re = if re.is_empty() { T::zero() } else { parse(re)? };
+        let im = match im {
+            "" | "+" => T::one(),
+            "-" => T::one().neg(),
+            _ => parse(im)?,
+        };
+
+        Ok(Self::new(re, im))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::{DivisionRing, Field, Involution};
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    /// Asserts that a type is a field.
+    fn assert_field<T: Field>() {}
+
+    #[test]
+    fn field() {
+        assert_field::<Complex<R<f64>>>();
+
+        let z = Complex::new(R(1.0), R(-2.0));
+        let w = Complex::new(R(0.5), R(3.0));
+        assert!(z.test_rec() && z.test_involution());
+        assert_eq!(z.div(&w).unwrap().mul(&w), z);
+        assert_eq!(Complex::<R<f64>>::zero().rec(), None);
+        assert_eq!(
+            Complex::<R<f64>>::i().mul(&Complex::i()),
+            Complex::from_scalar(R(-1.0))
+        );
+    }
+
+    #[test]
+    fn analysis() {
+        let z = Complex::new(R(3.0), R(4.0));
+        assert_eq!(R(z.abs()), R(5.0));
+
+        let (r, theta) = z.to_polar();
+        assert_eq!(Complex::from_polar(r, theta), z);
+
+        // Euler's identity.
+        let i_pi = Complex::new(R(0.0), R(PI));
+        assert_eq!(i_pi.exp(), Complex::from_scalar(R(-1.0)));
+        assert_eq!(Complex::from_scalar(R(-1.0)).ln(), Some(i_pi));
+        assert_eq!(z.ln().unwrap().exp(), z);
+        assert_eq!(Complex::<R<f64>>::zero().ln(), None);
+
+        for z in [
+            z,
+            Complex::new(R(-4.0), R(0.0)),
+            Complex::new(R(0.0), R(-2.0)),
+        ] {
+            let s = z.sqrt();
+            assert_eq!(s.mul(&s), z);
+            assert!(s.a.0 >= 0.0);
+        }
+
+        assert_eq!(R(Complex::<R<f64>>::i().arg()), R(FRAC_PI_2));
+    }
+
+    #[test]
+    fn gaussian() {
+        let samples: Vec<Complex<i64>> = (-3..=3)
+            .flat_map(|a| (-3..=3).map(move |b| Complex::new(a, b)))
+            .collect();
+
+        for a in &samples {
+            for b in &samples {
+                assert!(EuclideanDomain::test_div_rem(a, b));
+            }
+        }
+
+        // 5 = (2 + i)(2 - i), and 3 + 4i = (2 + i)².
+        let g = Complex::new(5, 0).gcd(&Complex::new(3, 4));
+        assert_eq!(g.norm(), 5);
+        assert_eq!(Complex::new(3, 4).div_rem(&g).unwrap().1, Complex::zero());
+
+        // Coefficients whose products overflow 64 bits.
+        let big = i64::MAX;
+        let a = Complex::new(big, -big);
+        let q = Complex::new(0, -big);
+        assert_eq!(a.div_rem(&Complex::new(1, 1)), Some((q, Complex::zero())));
+        let (q, r) = (Complex::new(1 << 62, 1 - (1 << 62)), Complex::new(-1, -1));
+        assert_eq!(a.div_rem(&Complex::new(2, 0)), Some((q, r)));
+        assert_eq!(a.div_rem(&Complex::new(1, 0)), Some((a, Complex::zero())));
+    }
+
+    #[test]
+    fn parse() {
+        let cases = [
+            ("3+4i", Complex::new(3, 4)),
+            ("3-4i", Complex::new(3, -4)),
+            ("-3 - 4i", Complex::new(-3, -4)),
+            ("7", Complex::new(7, 0)),
+            ("-2i", Complex::new(0, -2)),
+            ("i", Complex::new(0, 1)),
+            ("1-i", Complex::new(1, -1)),
+        ];
+
+        for (s, z) in cases {
+            assert_eq!(s.parse(), Ok(z));
+            assert_eq!(z.to_string().parse(), Ok(z));
+        }
+
+        assert_eq!(Complex::new(3, -4).to_string(), "3-4i");
+        assert_eq!(Complex::new(0, 0).to_string(), "0+0i");
+        assert_eq!(
+            "1.5e-3-2e+2i".parse(),
+            Ok(Complex::new(R(1.5e-3), R(-200.0)))
+        );
+        assert!("3+4j".parse::<Complex<i64>>().is_err());
+        assert!("inf".parse::<Complex<R<f64>>>().is_err());
+    }
+}
@@ -0,0 +1,220 @@
+//! Implements [hypercomplex numbers](https://en.wikipedia.org/wiki/Hypercomplex_number)
+//! over arbitrary rings: algebras spanned by a real unit and some imaginary
+//! units, built through the
+//! [Cayley–Dickson construction](https://en.wikipedia.org/wiki/Cayley%E2%80%93Dickson_construction).
+
+use algebra::{Add, Commutative, Conj, Group, Involution, Mul, Ring, Unital};
+
+use crate::R;
+
+/// Implements the componentwise additive group of a hypercomplex type, whose
+/// fields are all themselves additive groups, given the bounds on its
+/// coefficients.
+macro_rules! impl_additive {
+    ($type:ident: $($field:ident),*; [$($bound:tt)*]) => {
+        impl<T: $($bound)*> BinOp<Add> for $type<T> {
+            type Output = Self;
+            type Err = ();
+
+            fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+                Ok(Self {
+                    $($field: Magma::<Add>::op(&self.$field, &rhs.$field),)*
+                })
+            }
+        }
+
+        impl<T: $($bound)*> BinOp<Sub> for $type<T> {
+            type Output = Self;
+            type Err = ();
+
+            fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+                Ok(Self {
+                    $($field: Magma::<Add>::op(&self.$field, &Group::<Add>::inv(&rhs.$field)),)*
+                })
+            }
+        }
+
+        impl_assign!($type: Add, Sub; [$($bound)*]);
+
+        impl<T: $($bound)*> UnOp<Neg> for $type<T> {
+            type Output = Self;
+            type Err = ();
+
+            fn un_op(&self) -> Result<Self, ()> {
+                Ok(Self {
+                    $($field: Group::<Add>::inv(&self.$field),)*
+                })
+            }
+        }
+
+        impl<T: $($bound)*> UnOpAssign<Neg> for $type<T> {
+            type Err = ();
+
+            fn un_op_assign(&mut self) -> Result<(), ()> {
+                *self = UnOp::<Neg>::un_op(self)?;
+                Ok(())
+            }
+        }
+
+        impl<T: $($bound)*> Magma<Add> for $type<T> {}
+        impl<T: $($bound)*> Magma<Sub> for $type<T> {}
+        impl<T: $($bound)*> PowAssociative<Add> for $type<T> {}
+        impl<T: $($bound)*> Associative<Add> for $type<T> {}
+        impl<T: $($bound)*> Commutative<Add> for $type<T> {}
+
+        impl<T: $($bound)*> LeftUnital<Add> for $type<T> {
+            fn left_id() -> Self {
+                Self {
+                    $($field: LeftUnital::<Add>::left_id(),)*
+                }
+            }
+        }
+
+        impl<T: $($bound)*> RightUnital<Add> for $type<T> {
+            fn right_id() -> Self {
+                Self {
+                    $($field: RightUnital::<Add>::right_id(),)*
+                }
+            }
+        }
+
+        impl<T: $($bound)*> LeftQuasigroup<Add> for $type<T> {
+            type LInv = Bws<Sub>;
+        }
+
+        impl<T: $($bound)*> RightQuasigroup<Add> for $type<T> {
+            type RInv = Sub;
+        }
+
+        impl<T: $($bound)*> Group<Add> for $type<T> {
+            type Inv = Neg;
+        }
+    };
+    ($type:ident: $($field:ident),*) => {
+        impl_additive!($type: $($field),*; [Ring]);
+    };
+}
+
+/// Implements the assignment counterparts of some binary operations on a
+/// hypercomplex type, given the bounds on its coefficients.
+macro_rules! impl_assign {
+    (@impl $type:ident, $op:ty, [$($bound:tt)*]) => {
+        impl<T: $($bound)*> BinOpAssign<$op> for $type<T> {
+            type Err = ();
+
+            fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+                *self = BinOp::<$op>::bin_op(self, rhs)?;
+                Ok(())
+            }
+
+            fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+                *rhs = BinOp::<$op>::bin_op(self, rhs)?;
+                Ok(())
+            }
+        }
+    };
+    ($type:ident: $($op:ty),*; $bounds:tt) => {
+        $(
+            impl_assign!(@impl $type, $op, $bounds);
+        )*
+    };
+    ($type:ident: $($op:ty),*) => {
+        impl_assign!($type: $($op),*; [Ring]);
+    };
+}
+
+/// Declares the inherent `conj` method of a hypercomplex type as an
+/// [`Involution`](algebra::Involution) under [`Conj`](algebra::Conj).
+macro_rules! impl_conj {
+    ($type:ident; [$($bound:tt)*]) => {
+        impl<T: $($bound)*> UnOp<Conj> for $type<T> {
+            type Output = Self;
+            type Err = ();
+
+            fn un_op(&self) -> Result<Self, ()> {
+                Ok(self.conj())
+            }
+        }
+
+        impl<T: $($bound)*> UnOpAssign<Conj> for $type<T> {
+            type Err = ();
+
+            fn un_op_assign(&mut self) -> Result<(), ()> {
+                *self = self.conj();
+                Ok(())
+            }
+        }
+
+        impl<T: $($bound)*> Endomorphism<Conj> for $type<T> {}
+        impl<T: $($bound)*> Surjective<Conj> for $type<T> {}
+        impl<T: $($bound)*> Automorphism<Conj> for $type<T> {}
+        impl<T: $($bound)*> Involution<Conj> for $type<T> {}
+
+        impl<T: $($bound)*> Injective<Conj> for $type<T> {
+            type Inv = Conj;
+        }
+    };
+    ($type:ident) => {
+        impl_conj!($type; [Ring + Clone]);
+    };
+}
+
+/// A [*-algebra](https://en.wikipedia.org/wiki/*-algebra) over a commutative
+/// ring of scalars, which the [Cayley–Dickson construction](CayleyDickson) can
+/// be applied to.
+///
+/// Besides [`Conj`] being an involution, we assume that conjugation reverses
+/// products and fixes the scalars, and that both `x + x*` and `xx* = x*x` are
+/// scalars. The latter is what keeps every algebra built from the construction
+/// [flexible](algebra::Flexible).
+pub trait StarAlgebra:
+    Clone + Group<Add> + Commutative<Add> + Unital<Mul> + Involution<Conj>
+{
+    /// The ring of scalars.
+    type Scalar: Ring + Commutative<Mul> + Clone;
+
+    /// Embeds a scalar into the algebra.
+    fn from_scalar(k: Self::Scalar) -> Self;
+
+    /// Multiplies every coefficient by a scalar.
+    fn scale(&self, k: &Self::Scalar) -> Self;
+
+    /// Returns the norm `xx*`.
+    fn norm(&self) -> Self::Scalar;
+}
+
+/// Implements [`StarAlgebra`] for commutative rings with trivial conjugation,
+/// which are their own ring of scalars.
+macro_rules! impl_star_scalar {
+    ($($type:ty),*) => {
+        $(
+            impl StarAlgebra for $type {
+                type Scalar = Self;
+
+                fn from_scalar(k: Self) -> Self {
+                    k
+                }
+
+                fn scale(&self, k: &Self) -> Self {
+                    self.mul(k)
+                }
+
+                fn norm(&self) -> Self {
+                    self.mul(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_star_scalar!(i8, i16, i32, i64, i128, R<f32>, R<f64>);
+
+mod cayley_dickson;
+mod complex;
+mod octonion;
+mod quaternion;
+
+pub use cayley_dickson::*;
+pub use complex::*;
+pub use octonion::*;
+pub use quaternion::*;
@@ -0,0 +1,208 @@
+//! Implements the [octonions](https://en.wikipedia.org/wiki/Octonion) over a
+//! ring, and the Moufang loop of non-zero octonions over a field.
+
+use algebra::{
+    BinOp, BinOpAssign, Div, Field, Flexible, LDiv, LeftAlternative, LeftBolLoop,
+    LeftInverseProperty, LeftQuasigroup, LeftUnital, Magma, Mul, PowAssociative, RightAlternative,
+    RightBolLoop, RightInverseProperty, RightQuasigroup, RightUnital,
+};
+
+use super::{CayleyDickson, Quaternion, StarAlgebra};
+
+/// An [octonion](https://en.wikipedia.org/wiki/Octonion) with coefficients in
+/// a ring. This is built from a pair of quaternions `(a, b)` via the
+/// [Cayley–Dickson construction](CayleyDickson), so that it represents
+/// `a + bℓ` for a new imaginary unit `ℓ`.
+///
+/// Octonions aren't associative, but over a commutative ring they're
+/// [alternative](algebra::Alternative), and the ones with a non-zero norm form
+/// a [Moufang loop](algebra::MoufangLoop) under multiplication. See
+/// [`NonZeroOctonion`].
+pub type Octonion<T> = CayleyDickson<Quaternion<T>>;
+
+/// An octonion over a field with a non-zero norm, and thus a multiplicative
+/// inverse. Over the real numbers, these are exactly the non-zero octonions.
+///
+/// Since the norm is multiplicative, these form a [`MoufangLoop`](algebra::MoufangLoop)
+/// under multiplication, with left division `a \ b = a⁻¹b` and right division
+/// `b / a = ba⁻¹`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NonZeroOctonion<T>(Octonion<T>);
+
+impl<T: Field + Clone + PartialEq> NonZeroOctonion<T> {
+    /// Wraps an octonion, or returns `None` if its norm is zero.
+    pub fn new(x: Octonion<T>) -> Option<Self> {
+        (!x.norm().is_zero()).then_some(Self(x))
+    }
+}
+
+impl<T> NonZeroOctonion<T> {
+    /// Returns a reference to the octonion.
+    pub fn get(&self) -> &Octonion<T> {
+        &self.0
+    }
+
+    /// Returns the octonion.
+    pub fn into_inner(self) -> Octonion<T> {
+        self.0
+    }
+}
+
+impl<T: Field + Clone> BinOp<Mul> for NonZeroOctonion<T> {
+    type Output = Self;
+    type Err = ();
+
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        Ok(Self(Magma::<Mul>::op(&self.0, &rhs.0)))
+    }
+}
+
+impl<T: Field + Clone> BinOp<LDiv> for NonZeroOctonion<T> {
+    type Output = Self;
+    type Err = ();
+
+    /// Returns an error if the norm of `self` isn't invertible, which isn't a
+    /// "normal" circumstance.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        let rec = self.0.rec().ok_or(())?;
+        Ok(Self(Magma::<Mul>::op(&rec, &rhs.0)))
+    }
+}
+
+impl<T: Field + Clone> BinOp<Div> for NonZeroOctonion<T> {
+    type Output = Self;
+    type Err = ();
+
+    /// Returns an error if the norm of `rhs` isn't invertible, which isn't a
+    /// "normal" circumstance.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        let rec = rhs.0.rec().ok_or(())?;
+        Ok(Self(Magma::<Mul>::op(&self.0, &rec)))
+    }
+}
+
+/// Implements the assignment counterparts of the binary operations on
+/// non-zero octonions.
+macro_rules! impl_non_zero_assign {
+    ($($op:ty),*) => {
+        $(
+            impl<T: Field + Clone> BinOpAssign<$op> for NonZeroOctonion<T> {
+                type Err = ();
+
+                fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+                    *self = BinOp::<$op>::bin_op(self, rhs)?;
+                    Ok(())
+                }
+
+                fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+                    *rhs = BinOp::<$op>::bin_op(self, rhs)?;
+                    Ok(())
+                }
+            }
+
+            impl<T: Field + Clone> Magma<$op> for NonZeroOctonion<T> {}
+        )*
+    };
+}
+
+impl_non_zero_assign!(Mul, LDiv, Div);
+
+impl<T: Field + Clone> LeftUnital<Mul> for NonZeroOctonion<T> {
+    fn left_id() -> Self {
+        Self(Octonion::from_scalar(T::one()))
+    }
+}
+
+impl<T: Field + Clone> RightUnital<Mul> for NonZeroOctonion<T> {
+    fn right_id() -> Self {
+        Self(Octonion::from_scalar(T::one()))
+    }
+}
+
+impl<T: Field + Clone> LeftQuasigroup<Mul> for NonZeroOctonion<T> {
+    type LInv = LDiv;
+}
+
+impl<T: Field + Clone> RightQuasigroup<Mul> for NonZeroOctonion<T> {
+    type RInv = Div;
+}
+
+impl<T: Field + Clone> PowAssociative<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> Flexible<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> LeftAlternative<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> RightAlternative<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> LeftBolLoop<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> RightBolLoop<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> LeftInverseProperty<Mul> for NonZeroOctonion<T> {}
+impl<T: Field + Clone> RightInverseProperty<Mul> for NonZeroOctonion<T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::R;
+    use algebra::Involution;
+
+    /// Returns some octonions with small integer coefficients, converted by a
+    /// given function. These include the eight basis elements.
+    fn samples<T, F: Fn(i64) -> T>(f: F) -> Vec<Octonion<T>> {
+        let mut coefs: Vec<_> = (0..8)
+            .map(|i| [0, 1, 2, 3, 4, 5, 6, 7].map(|j| (i == j) as i64))
+            .collect();
+        coefs.push([1, -2, 0, 3, 1, 0, -1, 2]);
+        coefs.push([2, 1, 1, -1, 0, 3, 2, 1]);
+
+        coefs
+            .into_iter()
+            .map(|c| {
+                let [a, b, c, d, e, f, g, h] = c.map(&f);
+                Octonion::new(Quaternion::new(a, b, c, d), Quaternion::new(e, f, g, h))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn alternative() {
+        let samples = samples(|x| x);
+        let mul = Magma::<Mul>::op;
+        let mut associative = true;
+
+        for x in &samples {
+            assert!(PowAssociative::<Mul>::test_pow_assoc(x) && x.test_involution());
+            for y in &samples {
+                assert!(Octonion::test_flexible(x, y));
+                assert!(Octonion::test_left_alt(x, y));
+                assert!(Octonion::test_right_alt(x, y));
+                assert_eq!(mul(x, y).norm(), x.norm() * y.norm());
+
+                for z in &samples {
+                    associative &= mul(&mul(x, y), z) == mul(x, &mul(y, z));
+                }
+            }
+        }
+
+        assert!(!associative);
+    }
+
+    #[test]
+    fn moufang() {
+        let samples: Vec<_> = samples(|x| R(x as f64))
+            .into_iter()
+            .map(NonZeroOctonion::new)
+            .collect::<Option<_>>()
+            .unwrap();
+
+        for x in &samples {
+            for y in &samples {
+                assert!(x.test_left_div(y) && x.test_right_div(y));
+                assert!(NonZeroOctonion::test_left_inv_prop(x, y));
+                assert!(NonZeroOctonion::test_right_inv_prop(x, y));
+                for z in &samples {
+                    assert!(NonZeroOctonion::test_left_bol_loop(x, y, z));
+                    assert!(NonZeroOctonion::test_right_bol_loop(x, y, z));
+                }
+            }
+        }
+
+        assert!(NonZeroOctonion::new(Octonion::from_scalar(R(0.0))).is_none());
+    }
+}
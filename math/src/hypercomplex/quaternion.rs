@@ -0,0 +1,457 @@
+//! Implements the [quaternions](https://en.wikipedia.org/wiki/Quaternion) over a
+//! ring, together with their use as
+//! [rotations](https://en.wikipedia.org/wiki/Quaternions_and_spatial_rotation)
+//! of 3-space.
+
+use algebra::{
+    Add, Associative, Automorphism, BinOp, BinOpAssign, Bws, Commutative, Conj, DivisionRing,
+    Endomorphism, Field, Flexible, Group, Injective, Involution, LeftAlternative, LeftQuasigroup,
+    LeftUnital, Magma, Mul, Neg, PowAssociative, Rec, RightAlternative, RightQuasigroup,
+    RightUnital, Ring, Sub, Surjective, UnOp, UnOpAssign,
+};
+
+use super::StarAlgebra;
+use crate::{
+    matrix::{SquareMatrix, SquareMatrixS},
+    storage::Storage,
+    R,
+};
+
+/// A quaternion `a + bi + cj + dk` with coefficients in a ring, where
+/// `i² = j² = k² = ijk = -1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Quaternion<T> {
+    /// The real part.
+    pub a: T,
+
+    /// The coefficient of `i`.
+    pub b: T,
+
+    /// The coefficient of `j`.
+    pub c: T,
+
+    /// The coefficient of `k`.
+    pub d: T,
+}
+
+impl<T> Quaternion<T> {
+    /// Initializes the quaternion `a + bi + cj + dk`.
+    pub const fn new(a: T, b: T, c: T, d: T) -> Self {
+        Self { a, b, c, d }
+    }
+}
+
+impl<T: Ring> Quaternion<T> {
+    /// Initializes a quaternion with no imaginary part.
+    pub fn from_real(a: T) -> Self {
+        Self::new(a, T::zero(), T::zero(), T::zero())
+    }
+
+    /// Returns the norm `a² + b² + c² + d²`. This is the product of the
+    /// quaternion with its conjugate, and it's multiplicative whenever the
+    /// coefficients commute.
+    pub fn norm(&self) -> T {
+        let mut res = self.a.mul(&self.a);
+        for x in [&self.b, &self.c, &self.d] {
+            res.add_assign(&x.mul(x));
+        }
+
+        res
+    }
+
+    /// Multiplies every coefficient by a scalar on the right.
+    pub fn scale(&self, k: &T) -> Self {
+        Self::new(self.a.mul(k), self.b.mul(k), self.c.mul(k), self.d.mul(k))
+    }
+
+    /// Initializes the pure quaternion `xi + yj + zk` from a 3-vector.
+    pub fn from_vector([x, y, z]: [T; 3]) -> Self {
+        Self::new(T::zero(), x, y, z)
+    }
+}
+
+impl<T: Ring + Clone> Quaternion<T> {
+    /// Returns the conjugate `a - bi - cj - dk`.
+    pub fn conj(&self) -> Self {
+        Self::new(self.a.clone(), self.b.neg(), self.c.neg(), self.d.neg())
+    }
+
+    /// Returns the imaginary part `(b, c, d)` as a 3-vector.
+    pub fn vector(&self) -> [T; 3] {
+        [self.b.clone(), self.c.clone(), self.d.clone()]
+    }
+}
+
+impl<T: Field + Clone> Quaternion<T> {
+    /// Returns the inverse `q* / |q|²`, or `None` if the norm is zero.
+    ///
+    /// Over the reals, this only happens for the zero quaternion. Over other
+    /// fields, the norm of a non-zero quaternion might still vanish, as with
+    /// `1 + 2i` modulo 5.
+    pub fn rec(&self) -> Option<Self> {
+        self.norm().rec().map(|rec| self.conj().scale(&rec))
+    }
+
+    /// Rotates a 3-vector `v` by evaluating `qvq⁻¹`, where `v` is treated as a
+    /// pure quaternion. Every quaternion with non-zero norm acts as a rotation
+    /// this way, and `q` and `kq` give the same rotation for any non-zero
+    /// scalar `k`.
+    ///
+    /// # Panics
+    /// This method will panic if the norm of the quaternion is zero.
+    pub fn rotate(&self, v: [T; 3]) -> [T; 3] {
+        let rec = self
+            .rec()
+            .expect("a quaternion with zero norm isn't a rotation");
+        let res = Magma::<Mul>::op(&Magma::<Mul>::op(self, &Self::from_vector(v)), &rec);
+        [res.b, res.c, res.d]
+    }
+
+    /// Returns the matrix of the rotation given by [`Self::rotate`].
+    ///
+    /// # Panics
+    /// This method will panic if the norm of the quaternion is zero.
+    pub fn to_rotation_matrix(&self) -> SquareMatrixS<T, 3> {
+        let basis = |i| [0, 1, 2].map(|j| if i == j { T::one() } else { T::zero() });
+        let cols = [0, 1, 2].map(|j| self.rotate(basis(j)));
+        SquareMatrix::from_fn(3, |i, j| cols[j][i].clone())
+    }
+}
+
+impl Quaternion<R<f64>> {
+    /// Returns the coefficients as floats.
+    fn coefs(&self) -> [f64; 4] {
+        [self.a.0, self.b.0, self.c.0, self.d.0]
+    }
+
+    /// Initializes a quaternion from its coefficients as floats.
+    fn from_coefs([a, b, c, d]: [f64; 4]) -> Self {
+        Self::new(R(a), R(b), R(c), R(d))
+    }
+
+    /// Returns the quaternion scaled to have unit norm.
+    ///
+    /// # Panics
+    /// This method will panic if the quaternion is zero.
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm().0.sqrt();
+        assert!(norm != 0.0, "can't normalize the zero quaternion");
+        Self::from_coefs(self.coefs().map(|x| x / norm))
+    }
+
+    /// Returns the unit quaternion rotating by an angle in radians
+    /// counterclockwise around an axis.
+    ///
+    /// # Panics
+    /// This method will panic if the axis is zero.
+    pub fn from_axis_angle(axis: [R<f64>; 3], angle: f64) -> Self {
+        let [x, y, z] = Self::from_vector(axis).normalize().vector();
+        let (sin, cos) = (angle / 2.0).sin_cos();
+        Self::from_coefs([cos, x.0 * sin, y.0 * sin, z.0 * sin])
+    }
+
+    /// Returns the unit quaternion for a rotation matrix, using Shepperd's
+    /// method to avoid dividing by small numbers. The result is only determined
+    /// up to sign.
+    ///
+    /// # Panics
+    /// This method will panic if the matrix isn't `3 × 3`.
+    pub fn from_rotation_matrix<S: Storage<Inner = R<f64>>>(m: &SquareMatrix<S>) -> Self {
+        assert_eq!(m.dim(), 3, "rotation matrices must be 3 × 3");
+        let m = |i, j| m[(i, j)].0;
+        let diag = [m(0, 0), m(1, 1), m(2, 2)];
+        let trace: f64 = diag.iter().sum();
+
+        // Picks out the largest of the four squared coefficients, and computes
+        // the rest from it.
+        let res = if trace > diag[0].max(diag[1]).max(diag[2]) {
+            let s = (1.0 + trace).sqrt() * 2.0;
+            [
+                s / 4.0,
+                (m(2, 1) - m(1, 2)) / s,
+                (m(0, 2) - m(2, 0)) / s,
+                (m(1, 0) - m(0, 1)) / s,
+            ]
+        } else if diag[0] >= diag[1] && diag[0] >= diag[2] {
+            let s = (1.0 + 2.0 * diag[0] - trace).sqrt() * 2.0;
+            [
+                (m(2, 1) - m(1, 2)) / s,
+                s / 4.0,
+                (m(0, 1) + m(1, 0)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+            ]
+        } else if diag[1] >= diag[2] {
+            let s = (1.0 + 2.0 * diag[1] - trace).sqrt() * 2.0;
+            [
+                (m(0, 2) - m(2, 0)) / s,
+                (m(0, 1) + m(1, 0)) / s,
+                s / 4.0,
+                (m(1, 2) + m(2, 1)) / s,
+            ]
+        } else {
+            let s = (1.0 + 2.0 * diag[2] - trace).sqrt() * 2.0;
+            [
+                (m(1, 0) - m(0, 1)) / s,
+                (m(0, 2) + m(2, 0)) / s,
+                (m(1, 2) + m(2, 1)) / s,
+                s / 4.0,
+            ]
+        };
+
+        Self::from_coefs(res)
+    }
+
+    /// [Spherical linear interpolation](https://en.wikipedia.org/wiki/Slerp)
+    /// between the rotations given by two quaternions, which are normalized
+    /// first. The interpolation follows the shorter arc, so that `t = 0` and
+    /// `t = 1` give `self` and `±other` respectively.
+    ///
+    /// # Panics
+    /// This method will panic if either quaternion is zero.
+    pub fn slerp(&self, other: &Self, t: f64) -> Self {
+        let p = self.normalize().coefs();
+        let mut q = other.normalize().coefs();
+        let mut dot: f64 = p.iter().zip(&q).map(|(x, y)| x * y).sum();
+        if dot < 0.0 {
+            q = q.map(|x| -x);
+            dot = -dot;
+        }
+
+        // Nearby rotations are linearly interpolated, to avoid dividing by a
+        // tiny sine.
+        let (u, v) = if dot > 1.0 - R::<f64>::TOLERANCE {
+            (1.0 - t, t)
+        } else {
+            let theta = dot.acos();
+            let sin = theta.sin();
+            (((1.0 - t) * theta).sin() / sin, (t * theta).sin() / sin)
+        };
+
+        Self::from_coefs(std::array::from_fn(|i| u * p[i] + v * q[i])).normalize()
+    }
+}
+
+impl<T: Ring + Commutative<Mul> + Clone> StarAlgebra for Quaternion<T> {
+    type Scalar = T;
+
+    fn from_scalar(k: T) -> Self {
+        Self::from_real(k)
+    }
+
+    fn scale(&self, k: &T) -> Self {
+        Quaternion::scale(self, k)
+    }
+
+    fn norm(&self) -> T {
+        Quaternion::norm(self)
+    }
+}
+
+impl_additive!(Quaternion: a, b, c, d);
+impl_assign!(Quaternion: Mul);
+impl_conj!(Quaternion);
+
+impl<T: Ring> BinOp<Mul> for Quaternion<T> {
+    type Output = Self;
+    type Err = ();
+
+    /// Evaluates the Hamilton product.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        let (p, q) = (self, rhs);
+        let sum = |terms: [(&T, &T, bool); 4]| {
+            let mut res = T::zero();
+            for (x, y, neg) in terms {
+                let xy = x.mul(y);
+                if neg {
+                    res.sub_assign(&xy);
+                } else {
+                    res.add_assign(&xy);
+                }
+            }
+
+            res
+        };
+
+        Ok(Self::new(
+            sum([
+                (&p.a, &q.a, false),
+                (&p.b, &q.b, true),
+                (&p.c, &q.c, true),
+                (&p.d, &q.d, true),
+            ]),
+            sum([
+                (&p.a, &q.b, false),
+                (&p.b, &q.a, false),
+                (&p.c, &q.d, false),
+                (&p.d, &q.c, true),
+            ]),
+            sum([
+                (&p.a, &q.c, false),
+                (&p.b, &q.d, true),
+                (&p.c, &q.a, false),
+                (&p.d, &q.b, false),
+            ]),
+            sum([
+                (&p.a, &q.d, false),
+                (&p.b, &q.c, false),
+                (&p.c, &q.b, true),
+                (&p.d, &q.a, false),
+            ]),
+        ))
+    }
+}
+
+impl<T: Ring> Magma<Mul> for Quaternion<T> {}
+
+impl<T: Ring> LeftUnital<Mul> for Quaternion<T> {
+    fn left_id() -> Self {
+        Self::from_real(T::one())
+    }
+}
+
+impl<T: Ring> RightUnital<Mul> for Quaternion<T> {
+    fn right_id() -> Self {
+        Self::from_real(T::one())
+    }
+}
+
+// The quaternions over any ring form a ring, though conjugation is only an
+// anti-automorphism, and the norm only multiplicative, when the coefficients
+// commute.
+impl<T: Ring> PowAssociative<Mul> for Quaternion<T> {}
+impl<T: Ring> Associative<Mul> for Quaternion<T> {}
+impl<T: Ring> Flexible<Mul> for Quaternion<T> {}
+impl<T: Ring> LeftAlternative<Mul> for Quaternion<T> {}
+impl<T: Ring> RightAlternative<Mul> for Quaternion<T> {}
+impl<T: Ring> Ring for Quaternion<T> {}
+
+impl<T: Field + Clone> UnOp<Rec> for Quaternion<T> {
+    type Output = Self;
+    type Err = ();
+
+    /// Evaluates `q* / |q|²`, or returns an error if the norm isn't
+    /// invertible.
+    fn un_op(&self) -> Result<Self, ()> {
+        self.rec().ok_or(())
+    }
+}
+
+impl<T: Field + Clone> UnOpAssign<Rec> for Quaternion<T> {
+    type Err = ();
+
+    fn un_op_assign(&mut self) -> Result<(), ()> {
+        *self = UnOp::<Rec>::un_op(self)?;
+        Ok(())
+    }
+}
+
+// Over other fields, such as the integers modulo 5 or the complex numbers,
+// some non-zero quaternions have zero norm.
+impl DivisionRing for Quaternion<R<f32>> {}
+impl DivisionRing for Quaternion<R<f64>> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hamilton() {
+        let (one, i, j, k) = (
+            Quaternion::new(1, 0, 0, 0),
+            Quaternion::new(0, 1, 0, 0),
+            Quaternion::new(0, 0, 1, 0),
+            Quaternion::new(0, 0, 0, 1),
+        );
+
+        assert_eq!(i.mul(&j), k);
+        assert_eq!(j.mul(&i), k.neg());
+        assert_eq!(i.mul(&i), one.neg());
+        assert_eq!(i.mul(&j).mul(&k), one.neg());
+
+        let q = Quaternion::new(1, -2, 3, 4);
+        assert_eq!(q.mul(&q.conj()), Quaternion::from_real(q.norm()));
+        assert!(q.test_involution());
+        assert_ne!(q.mul(&i), i.mul(&q));
+    }
+
+    #[test]
+    fn division() {
+        let r = |a, b, c, d| Quaternion::new(R(a), R(b), R(c), R(d));
+        let samples = [
+            r(0.0, 0.0, 0.0, 0.0),
+            r(1.0, 0.0, 0.0, 0.0),
+            r(0.0, 1.0, 0.0, 0.0),
+            r(1.0, -2.0, 3.0, 4.0),
+            r(0.5, 0.25, -3.0, 2.0),
+        ];
+
+        for p in &samples {
+            assert!(p.test_rec());
+            for q in &samples {
+                assert_eq!(p.mul(q).conj(), q.conj().mul(&p.conj()));
+                assert_eq!(p.mul(q).norm(), p.norm().mul(&q.norm()));
+            }
+        }
+
+        // A zero divisor, since (1 + 2i)(1 - 2i) = 5.
+        let z = |a| crate::modular::Zn::<5>::new(a);
+        let q = Quaternion::new(z(1), z(2), z(0), z(0));
+        assert_eq!(q.rec(), None);
+        assert!(q.mul(&q.conj()).is_zero());
+    }
+
+    #[test]
+    fn rotation() {
+        let r = |v: [f64; 3]| v.map(R);
+        let (x, y, z) = (r([1.0, 0.0, 0.0]), r([0.0, 1.0, 0.0]), r([0.0, 0.0, 1.0]));
+
+        // A quarter turn around the z-axis.
+        let q = Quaternion::from_axis_angle(z, std::f64::consts::FRAC_PI_2);
+        assert_eq!(q.rotate(x), y);
+        assert_eq!(q.rotate(z), z);
+
+        // A third of a turn around (1, 1, 1), as a non-unit quaternion.
+        let q = Quaternion::new(R(1.0), R(1.0), R(1.0), R(1.0));
+        assert_eq!(q.rotate(x), y);
+        assert_eq!(q.rotate(y), z);
+
+        let samples = [
+            q,
+            Quaternion::new(R(0.0), R(1.0), R(0.0), R(0.0)),
+            Quaternion::new(R(0.1), R(0.2), R(-3.0), R(0.4)),
+            Quaternion::new(R(0.2), R(-1.0), R(0.5), R(4.0)),
+            Quaternion::new(R(-2.0), R(0.1), R(0.0), R(0.3)),
+        ];
+
+        for q in samples {
+            let m = q.to_rotation_matrix();
+            assert_eq!(m.det(), R(1.0));
+            assert_eq!(
+                SquareMatrixS::<_, 3>::product(&m, &m.transpose()),
+                SquareMatrixS::identity(3)
+            );
+
+            let p = Quaternion::from_rotation_matrix(&m);
+            let q = q.normalize();
+            assert!(p == q || p == q.neg());
+        }
+    }
+
+    #[test]
+    fn slerp() {
+        let z = [R(0.0), R(0.0), R(1.0)];
+        let id = Quaternion::from_real(R(1.0));
+        let q = Quaternion::from_axis_angle(z, std::f64::consts::FRAC_PI_2);
+
+        assert_eq!(id.slerp(&q, 0.0), id);
+        assert_eq!(id.slerp(&q, 1.0), q);
+        assert_eq!(
+            id.slerp(&q, 0.5),
+            Quaternion::from_axis_angle(z, std::f64::consts::FRAC_PI_4)
+        );
+
+        // The interpolation takes the shorter arc.
+        assert_eq!(id.slerp(&q.neg(), 0.5), id.slerp(&q, 0.5));
+        assert_eq!(q.slerp(&q.scale(&R(3.0)), 0.5), q);
+    }
+}
@@ -1,7 +1,60 @@
 //! Implements various numerical types.
 
+// Allows the macros to refer to this crate by name.
+extern crate self as math;
+
+pub mod bignat;
+pub mod cayley;
+pub mod hypercomplex;
+pub mod matrix;
+pub mod modular;
+pub mod partial_permutation;
 pub mod permutation;
+pub mod random;
+mod real;
 pub mod storage;
+pub mod transformation;
+
+/// Builds a statically-sized finite magma from its Cayley table, validating it
+/// at compile time.
+///
+/// ```
+/// use math::{cayley::CayleyMagmaS, cayley_table};
+///
+/// const Z3: CayleyMagmaS<3> = cayley_table![[0, 1, 2], [1, 2, 0], [2, 0, 1]];
+/// assert!(Z3.properties().is_group());
+/// ```
+///
+/// Tables that aren't square or have out of range entries are rejected.
+///
+/// ```compile_fail
+/// math::cayley_table![[0, 1], [1]];
+/// ```
+///
+/// ```compile_fail
+/// math::cayley_table![[0, 1], [1, 2]];
+/// ```
+pub use macros::cayley_table;
+
+/// Builds a statically-sized permutation, validating it at compile time.
+///
+/// ```
+/// use math::{perm, permutation::PermutationS};
+///
+/// assert_eq!(perm![2, 0, 1], PermutationS::new([2, 0, 1].into()).unwrap());
+/// assert_eq!(perm!((0 1)(2 3); 5).iter().collect::<Vec<_>>(), vec![1, 0, 3, 2, 4]);
+/// ```
+///
+/// Invalid permutations are rejected.
+///
+/// ```compile_fail
+/// math::perm![0, 2, 2];
+/// ```
+///
+/// ```compile_fail
+/// math::perm!((0 5); 4);
+/// ```
+pub use macros::perm;
 
 /// A wrapper for wrapping operations. This amounts to taking a numerical type
 /// modulo some power of two.
@@ -29,7 +82,8 @@ pub struct N<T>(T);
 ///   assumed to not be `NaN`.
 /// - Reals are unbounded, which means we'll disallow `Infinity` and
 ///   `-Infinity`.
-pub struct R<T>(T);
+#[derive(Clone, Copy, Debug, Default)]
+pub struct R<T>(pub T);
 
 /// A wrapper for floating point numbers, which states they are to be treated
 /// as [projectively extended real numbers](https://en.wikipedia.org/wiki/Projectively_extended_real_line).
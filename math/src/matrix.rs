@@ -0,0 +1,317 @@
+//! Implements dense square matrices over rings.
+
+use std::ops::{Index, IndexMut, Mul};
+
+use algebra::{DivisionRing, Field, Ring};
+
+use crate::{
+    permutation::{Parity, PermutationD},
+    storage::{ArrayStorage, ArrayStorageE, OwnedStorage, Size, Storage, StorageMut, VecStorageE},
+};
+
+/// A square matrix. This is stored in row-major order as some storage whose
+/// length is the square of the dimension of the matrix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SquareMatrix<S: Storage> {
+    /// The entries of the matrix, in row-major order.
+    data: S,
+
+    /// The number of rows or columns of the matrix.
+    dim: usize,
+}
+
+/// A statically-sized `N × N` matrix, backed by an [`ArrayStorage`].
+pub type SquareMatrixS<T, const N: usize> = SquareMatrix<ArrayStorage<ArrayStorageE<T, N>, N>>;
+
+/// A dynamically-sized square matrix, backed by a [`VecStorage`].
+pub type SquareMatrixD<T> = SquareMatrix<VecStorageE<T>>;
+
+impl<S: Storage> Index<(usize, usize)> for SquareMatrix<S> {
+    type Output = S::Inner;
+
+    /// Returns the entry at a given row and column.
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(i < self.dim && j < self.dim, "index out of bounds");
+        &self.data[i * self.dim + j]
+    }
+}
+
+impl<S: StorageMut> IndexMut<(usize, usize)> for SquareMatrix<S> {
+    /// Returns a mutable reference to the entry at a given row and column.
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        assert!(i < self.dim && j < self.dim, "index out of bounds");
+        &mut self.data[i * self.dim + j]
+    }
+}
+
+impl<S: Storage> SquareMatrix<S> {
+    /// Initializes a new matrix from its entries in row-major order. Returns
+    /// `None` if the length of the storage isn't a perfect square.
+    pub fn new(data: S) -> Option<Self> {
+        let len = data.len();
+        let mut dim = (len as f64).sqrt() as usize;
+
+        // Corrects for any rounding errors.
+        while dim * dim > len {
+            dim -= 1;
+        }
+        while (dim + 1) * (dim + 1) <= len {
+            dim += 1;
+        }
+
+        (dim * dim == len).then_some(Self { data, dim })
+    }
+
+    /// Returns the number of rows or columns of the matrix.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Returns the underlying storage of the matrix.
+    pub fn into_storage(self) -> S {
+        self.data
+    }
+
+    /// Returns an iterator over the entries of a given row.
+    ///
+    /// # Panics
+    /// This method will panic if the row is out of bounds.
+    pub fn row(&self, i: usize) -> impl Iterator<Item = &S::Inner> {
+        assert!(i < self.dim, "index out of bounds");
+        (0..self.dim).map(move |j| &self.data[i * self.dim + j])
+    }
+
+    /// Returns an iterator over the entries of a given column.
+    ///
+    /// # Panics
+    /// This method will panic if the column is out of bounds.
+    pub fn col(&self, j: usize) -> impl Iterator<Item = &S::Inner> {
+        assert!(j < self.dim, "index out of bounds");
+        (0..self.dim).map(move |i| &self.data[i * self.dim + j])
+    }
+
+    /// Returns the determinant of the matrix, using the
+    /// [Leibniz formula](https://en.wikipedia.org/wiki/Leibniz_formula_for_determinants)
+    ///
+    /// ```txt
+    /// det(A) = Σ sgn(σ) A[σ(0), 0] A[σ(1), 1] ⋯ A[σ(n - 1), n - 1].
+    /// ```
+    ///
+    /// This works over any commutative ring, but takes `O(n · n!)` time, so
+    /// it's only suitable for small matrices. The permutations are visited
+    /// using [Heap's algorithm](https://en.wikipedia.org/wiki/Heap%27s_algorithm),
+    /// which performs a single transposition at each step.
+    pub fn det_leibniz(&self) -> S::Inner
+    where
+        S::Inner: Ring + Clone,
+    {
+        let n = self.dim;
+        let mut perm = PermutationD::identity(Size::from_usize(n));
+        let mut parity = Parity::Even;
+        let mut det = S::Inner::zero();
+
+        let mut add_term = |perm: &PermutationD, parity: Parity| {
+            let mut term: S::Inner = parity.sign();
+            for j in 0..n {
+                term.mul_assign(&self[(perm[j], j)]);
+            }
+            det.add_assign(&term);
+        };
+
+        add_term(&perm, parity);
+        let mut counter = vec![0; n];
+        let mut i = 1;
+        while i < n {
+            if counter[i] < i {
+                let j = if i % 2 == 0 { 0 } else { counter[i] };
+                perm.swap(j, i);
+                parity.flip_mut();
+                add_term(&perm, parity);
+
+                counter[i] += 1;
+                i = 1;
+            } else {
+                counter[i] = 0;
+                i += 1;
+            }
+        }
+
+        det
+    }
+
+    /// Returns the determinant of the matrix, using
+    /// [Gaussian elimination](https://en.wikipedia.org/wiki/Gaussian_elimination).
+    /// This takes `O(n³)` time, but requires the entries to form a field.
+    pub fn det(&self) -> S::Inner
+    where
+        S::Inner: Field + Clone + PartialEq,
+    {
+        let n = self.dim;
+        let mut rows: Vec<Vec<S::Inner>> = (0..n).map(|i| self.row(i).cloned().collect()).collect();
+        let mut det = S::Inner::one();
+
+        for k in 0..n {
+            let pivot = match (k..n).find(|&i| !rows[i][k].is_zero()) {
+                Some(pivot) => pivot,
+                None => return S::Inner::zero(),
+            };
+
+            if pivot != k {
+                rows.swap(pivot, k);
+                det = det.neg();
+            }
+
+            det.mul_assign(&rows[k][k]);
+            let inv = rows[k][k].rec().unwrap();
+
+            let (top, bottom) = rows.split_at_mut(k + 1);
+            let pivot_row = &top[k];
+            for row in bottom {
+                let factor = row[k].mul(&inv);
+                for (x, y) in row.iter_mut().zip(pivot_row).skip(k) {
+                    x.sub_assign(&factor.mul(y));
+                }
+            }
+        }
+
+        det
+    }
+}
+
+impl<S: OwnedStorage> SquareMatrix<S> {
+    /// Initializes a matrix of a given dimension, whose entry at row `i` and
+    /// column `j` is `f(i, j)`.
+    ///
+    /// # Panics
+    /// This method will panic if the square of the dimension is incompatible
+    /// with the size of the storage.
+    pub fn from_fn<F: FnMut(usize, usize) -> S::Inner>(dim: usize, mut f: F) -> Self {
+        Size::<S>::from_usize(dim * dim);
+
+        Self {
+            data: (0..dim * dim).map(|k| f(k / dim, k % dim)).collect(),
+            dim,
+        }
+    }
+
+    /// Returns the zero matrix of a given dimension.
+    ///
+    /// # Panics
+    /// This method will panic if the square of the dimension is incompatible
+    /// with the size of the storage.
+    pub fn zero(dim: usize) -> Self
+    where
+        S::Inner: Ring,
+    {
+        Self::from_fn(dim, |_, _| S::Inner::zero())
+    }
+
+    /// Returns the identity matrix of a given dimension.
+    ///
+    /// # Panics
+    /// This method will panic if the square of the dimension is incompatible
+    /// with the size of the storage.
+    pub fn identity(dim: usize) -> Self
+    where
+        S::Inner: Ring,
+    {
+        Self::from_fn(dim, |i, j| {
+            if i == j {
+                S::Inner::one()
+            } else {
+                S::Inner::zero()
+            }
+        })
+    }
+
+    /// Returns the transpose of the matrix.
+    pub fn transpose(&self) -> Self
+    where
+        S::Inner: Clone,
+    {
+        Self::from_fn(self.dim, |i, j| self[(j, i)].clone())
+    }
+
+    /// Multiplies two matrices together.
+    ///
+    /// # Panics
+    /// This method will panic if the dimensions of the matrices differ.
+    pub fn product<A: Storage<Inner = S::Inner>, B: Storage<Inner = S::Inner>>(
+        a: &SquareMatrix<A>,
+        b: &SquareMatrix<B>,
+    ) -> Self
+    where
+        S::Inner: Ring,
+    {
+        assert_eq!(a.dim, b.dim, "dimension mismatch");
+
+        Self::from_fn(a.dim, |i, j| {
+            let mut sum = S::Inner::zero();
+            for (x, y) in a.row(i).zip(b.col(j)) {
+                sum.add_assign(&x.mul(y));
+            }
+            sum
+        })
+    }
+}
+
+impl<S: OwnedStorage> Mul<&SquareMatrix<S>> for &SquareMatrix<S>
+where
+    S::Inner: Ring,
+{
+    type Output = SquareMatrix<S>;
+
+    fn mul(self, rhs: &SquareMatrix<S>) -> Self::Output {
+        SquareMatrix::product(self, rhs)
+    }
+}
+
+impl<S: OwnedStorage> Mul for SquareMatrix<S>
+where
+    S::Inner: Ring,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random::Rng, R};
+
+    #[test]
+    fn product() {
+        let a = SquareMatrixS::<i64, 2>::new(ArrayStorage([[1, 2].into(), [3, 4].into()])).unwrap();
+        let b = SquareMatrixS::<i64, 2>::from_fn(2, |i, j| (i + 2 * j) as i64);
+        assert_eq!(
+            (a * b).into_storage(),
+            ArrayStorage([[2, 8].into(), [4, 18].into()])
+        );
+        assert_eq!(a * SquareMatrixS::identity(2), a);
+        assert_eq!(a.transpose()[(0, 1)], 3);
+        assert!(SquareMatrixD::new([0; 5].iter().copied().collect::<VecStorageE<i32>>()).is_none());
+    }
+
+    #[test]
+    fn det() {
+        let a =
+            SquareMatrixS::<i64, 3>::from_fn(3, |i, j| [[2, -1, 0], [1, 3, 4], [0, 5, -2]][i][j]);
+        assert_eq!(a.det_leibniz(), -54);
+        assert_eq!(SquareMatrixD::<i64>::zero(0).det_leibniz(), 1);
+
+        // Cross-checks both methods on random integer matrices.
+        let mut rng = Rng::new(5);
+        for n in 1..=5 {
+            let a = SquareMatrixD::<i64>::from_fn(n, |_, _| rng.below(11) as i64 - 5);
+            let b = SquareMatrixD::<R<f64>>::from_fn(n, |i, j| R(a[(i, j)] as f64));
+            assert_eq!(R(a.det_leibniz() as f64), b.det());
+            assert_eq!(b.det_leibniz(), b.det());
+        }
+
+        let singular = SquareMatrixD::<R<f64>>::from_fn(3, |i, j| R((i * j) as f64));
+        assert_eq!(singular.det(), R(0.0));
+    }
+}
@@ -0,0 +1,193 @@
+//! Implements the [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem),
+//! which reconstructs an integer from its residues modulo several moduli.
+
+use std::fmt::{self, Display};
+
+use super::{add_mod, gcd, inv_mod, mul_mod, sub_mod};
+use crate::bignat::BigNat;
+
+/// The reasons a system of congruences may fail to be solved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CrtError {
+    /// The number of residues and moduli differ.
+    LengthMismatch,
+
+    /// The modulus at a given index is zero.
+    ZeroModulus(usize),
+
+    /// The congruence at a given index contradicts the previous ones.
+    Inconsistent(usize),
+
+    /// The modulus at a given index isn't coprime to the previous ones.
+    NotCoprime(usize),
+}
+
+impl Display for CrtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthMismatch => write!(f, "the number of residues and moduli differ"),
+            Self::ZeroModulus(i) => write!(f, "modulus {} is zero", i),
+            Self::Inconsistent(i) => write!(f, "congruence {} contradicts the previous ones", i),
+            Self::NotCoprime(i) => write!(f, "modulus {} isn't coprime to the previous ones", i),
+        }
+    }
+}
+
+impl std::error::Error for CrtError {}
+
+/// Solves the system of congruences `x ≡ rᵢ (mod mᵢ)`, and returns its least
+/// non-negative solution `x` together with the least common multiple of the
+/// moduli, modulo which the solution is unique.
+///
+/// The moduli need not be coprime, in which case every congruence must agree
+/// with the previous ones modulo their common divisors. An empty system is
+/// solved by `x = 0` modulo 1.
+///
+/// ```
+/// use math::{bignat::BigNat, modular::crt::{crt, CrtError}};
+///
+/// assert_eq!(crt(&[2, 3, 2], &[3, 5, 7]), Ok((BigNat::from(23u64), BigNat::from(105u64))));
+/// assert_eq!(crt(&[1, 3], &[4, 6]), Ok((BigNat::from(9u64), BigNat::from(12u64))));
+/// assert_eq!(crt(&[1, 2], &[4, 6]), Err(CrtError::Inconsistent(1)));
+/// ```
+pub fn crt(residues: &[u64], moduli: &[u64]) -> Result<(BigNat, BigNat), CrtError> {
+    if residues.len() != moduli.len() {
+        return Err(CrtError::LengthMismatch);
+    }
+
+    let mut x = BigNat::zero();
+    let mut lcm = BigNat::one();
+
+    for (i, (&r, &m)) in residues.iter().zip(moduli).enumerate() {
+        if m == 0 {
+            return Err(CrtError::ZeroModulus(i));
+        }
+
+        // We look for t with x + lcm t ≡ r (mod m). Dividing by g = gcd(lcm, m)
+        // leaves a congruence modulo m / g whose coefficient is a unit.
+        let l = lcm.rem_small(m);
+        let g = gcd(l, m);
+        let diff = sub_mod(r % m, x.rem_small(m), m);
+        if !diff.is_multiple_of(g) {
+            return Err(CrtError::Inconsistent(i));
+        }
+
+        let m = m / g;
+        let inv = inv_mod(l / g, m).expect("the coefficient should be a unit");
+        let mut step = lcm.clone();
+        step.mul_small(mul_mod(diff / g, inv, m));
+
+        x = &x + &step;
+        lcm.mul_small(m);
+    }
+
+    Ok((x, lcm))
+}
+
+/// Returns the mixed-radix digits of the solution to the system of
+/// congruences `x ≡ rᵢ (mod mᵢ)`, using
+/// [Garner's algorithm](https://en.wikipedia.org/wiki/Mixed_radix). These are
+/// the `vᵢ < mᵢ` with
+///
+/// `x = v₀ + v₁m₀ + v₂m₀m₁ + ⋯`
+///
+/// and the solution can be rebuilt through [`from_mixed_radix`]. Unlike
+/// [`crt`], this only uses word arithmetic, but it requires the moduli to be
+/// pairwise coprime.
+///
+/// ```
+/// use math::modular::crt::{from_mixed_radix, garner, CrtError};
+///
+/// let digits = garner(&[2, 3, 2], &[3, 5, 7]).unwrap();
+/// assert_eq!(digits, [2, 2, 1]);
+/// assert_eq!(from_mixed_radix(&digits, &[3, 5, 7]).to_u128(), Some(23));
+/// assert_eq!(garner(&[1, 3], &[4, 6]), Err(CrtError::NotCoprime(1)));
+/// ```
+pub fn garner(residues: &[u64], moduli: &[u64]) -> Result<Vec<u64>, CrtError> {
+    if residues.len() != moduli.len() {
+        return Err(CrtError::LengthMismatch);
+    }
+
+    let mut digits = Vec::with_capacity(moduli.len());
+    for (i, (&r, &m)) in residues.iter().zip(moduli).enumerate() {
+        if m == 0 {
+            return Err(CrtError::ZeroModulus(i));
+        }
+
+        // The value of the previous digits modulo m, and the product of the
+        // previous moduli.
+        let (mut acc, mut prod) = (0, 1 % m);
+        for (&v, &n) in digits.iter().zip(moduli) {
+            acc = add_mod(acc, mul_mod(v, prod, m), m);
+            prod = mul_mod(prod, n, m);
+        }
+
+        let inv = inv_mod(prod, m).ok_or(CrtError::NotCoprime(i))?;
+        digits.push(mul_mod(sub_mod(r % m, acc, m), inv, m));
+    }
+
+    Ok(digits)
+}
+
+/// Evaluates `v₀ + v₁m₀ + v₂m₀m₁ + ⋯` for some digits `vᵢ` and radices `mᵢ`.
+///
+/// # Panics
+/// This function will panic if there's more digits than radices.
+pub fn from_mixed_radix(digits: &[u64], radices: &[u64]) -> BigNat {
+    assert!(digits.len() <= radices.len(), "not enough radices");
+
+    let mut x = BigNat::zero();
+    for (&v, &m) in digits.iter().zip(radices).rev() {
+        x.mul_small(m);
+        x.add_small(v);
+    }
+
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+
+    #[test]
+    fn coprime() {
+        let moduli = [u64::MAX, u64::MAX - 1, (1 << 61) - 1, 1_000_000_007];
+        let mut rng = Rng::new(50);
+
+        for _ in 0..50 {
+            let residues = moduli.map(|m| rng.below(m));
+            let (x, lcm) = crt(&residues, &moduli).unwrap();
+
+            for (&r, &m) in residues.iter().zip(&moduli) {
+                assert_eq!(x.rem_small(m), r);
+            }
+
+            let digits = garner(&residues, &moduli).unwrap();
+            assert_eq!(from_mixed_radix(&digits, &moduli), x);
+            assert_eq!(
+                moduli.iter().fold(BigNat::one(), |mut p, &m| {
+                    p.mul_small(m);
+                    p
+                }),
+                lcm
+            );
+        }
+    }
+
+    #[test]
+    fn non_coprime() {
+        // Both x ≡ 3 (mod 4) and x ≡ 5 (mod 6) follow from x ≡ 11 (mod 12).
+        let (x, lcm) = crt(&[3, 5, 11], &[4, 6, 12]).unwrap();
+        assert_eq!((x.to_u128(), lcm.to_u128()), (Some(11), Some(12)));
+
+        let (x, lcm) = crt(&[1 << 30, 0], &[1 << 40, 1 << 20]).unwrap();
+        assert_eq!((x.to_u128(), lcm.to_u128()), (Some(1 << 30), Some(1 << 40)));
+
+        assert_eq!(crt(&[], &[]), Ok((BigNat::zero(), BigNat::one())));
+        assert_eq!(crt(&[3, 4], &[6, 9]), Err(CrtError::Inconsistent(1)));
+        assert_eq!(crt(&[1], &[0]), Err(CrtError::ZeroModulus(0)));
+        assert_eq!(crt(&[1], &[2, 3]), Err(CrtError::LengthMismatch));
+        assert_eq!(garner(&[1, 1], &[6, 9]), Err(CrtError::NotCoprime(1)));
+    }
+}
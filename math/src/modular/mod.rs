@@ -0,0 +1,754 @@
+//! Implements the [integers modulo n](https://en.wikipedia.org/wiki/Modular_arithmetic),
+//! both for a modulus known at compile time and for one chosen at runtime.
+//!
+//! For 64 and 128-bit moduli known at compile time, the [`montgomery`] and
+//! [`barrett`] modules provide representations whose multiplication avoids
+//! hardware division altogether. The [`crt`] and [`rns`] modules combine
+//! residues modulo several moduli.
+
+pub mod barrett;
+pub mod crt;
+pub mod montgomery;
+pub mod rns;
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use algebra::{
+    algebraic_structure, bin_op, un_op, Bws, DivisionRing, Endomorphism, Finite, Group, Injective,
+    LeftQuasigroup, LeftUnital, Rec, RightQuasigroup, RightUnital, UnOp, UnOpAssign,
+};
+
+/// Returns `a + b mod m`, for `a, b < m`.
+const fn add_mod(a: u64, b: u64, m: u64) -> u64 {
+    let (sum, overflow) = a.overflowing_add(b);
+    if overflow || sum >= m {
+        sum.wrapping_sub(m)
+    } else {
+        sum
+    }
+}
+
+/// Returns `a - b mod m`, for `a, b < m`.
+const fn sub_mod(a: u64, b: u64, m: u64) -> u64 {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Returns `-a mod m`, for `a < m`.
+const fn neg_mod(a: u64, m: u64) -> u64 {
+    if a == 0 {
+        0
+    } else {
+        m - a
+    }
+}
+
+/// Returns `ab mod m`.
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64 {
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+/// Returns the high and low words of the full product `ab`.
+const fn mul_wide_u64(a: u64, b: u64) -> (u64, u64) {
+    let prod = a as u128 * b as u128;
+    ((prod >> 64) as u64, prod as u64)
+}
+
+/// Returns the high and low words of the full product `ab`, by splitting both
+/// factors into 64-bit halves.
+const fn mul_wide_u128(a: u128, b: u128) -> (u128, u128) {
+    const LOW: u128 = u64::MAX as u128;
+    let (a1, a0) = (a >> 64, a & LOW);
+    let (b1, b0) = (b >> 64, b & LOW);
+
+    let ll = a0 * b0;
+    let lh = a0 * b1;
+    let hl = a1 * b0;
+    let hh = a1 * b1;
+
+    let mid = (ll >> 64) + (lh & LOW) + (hl & LOW);
+    let lo = (ll & LOW) | (mid << 64);
+    let hi = hh + (lh >> 64) + (hl >> 64) + (mid >> 64);
+    (hi, lo)
+}
+
+/// Returns `bᵉ mod m`, using exponentiation by squares.
+const fn pow_mod(mut b: u64, mut e: u64, m: u64) -> u64 {
+    let mut res = 1 % m;
+    b %= m;
+    while e > 0 {
+        if e % 2 == 1 {
+            res = mul_mod(res, b, m);
+        }
+        b = mul_mod(b, b, m);
+        e /= 2;
+    }
+
+    res
+}
+
+/// Returns whether a number is prime, using a
+/// [Miller–Rabin test](https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test)
+/// on the first twelve primes, which is deterministic for 64-bit integers.
+pub const fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    // Writes n - 1 = 2ˢd with d odd.
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    let mut i = 0;
+    'bases: while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+
+        return false;
+    }
+
+    true
+}
+
+/// Returns the greatest common divisor of two numbers.
+const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Returns the inverse of `a` modulo `m`, or `None` if they aren't coprime.
+/// This uses the [extended Euclidean algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm).
+///
+/// # Panics
+/// This function will panic if `m` is zero.
+pub fn inv_mod(a: u64, m: u64) -> Option<u64> {
+    let (mut r0, mut r1) = (m as i128, (a % m) as i128);
+    let (mut t0, mut t1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 - q * t1);
+    }
+
+    (r0 == 1).then(|| t0.rem_euclid(m as i128) as u64)
+}
+
+/// An integer modulo a positive constant `M`, stored as its least
+/// non-negative residue.
+///
+/// These form a commutative [`Ring`](algebra::Ring), where the units are the
+/// residues coprime to `M`, and a [`Field`](algebra::Field) when `M` is prime.
+///
+/// Stable Rust can't bound a trait implementation on `M` being prime, so this
+/// type implements [`DivisionRing`](algebra::DivisionRing) for every modulus.
+/// Only its methods are guarded, which fail to compile when the modulus is
+/// composite. The reciprocals of units modulo any `M` are still available
+/// through [`UnOp<Rec>`](algebra::UnOp), which fails on non-units. Since it's
+/// partial, [`Rec`] is only an [`Injective`] operation on the group of units
+/// [`ZnUnit`].
+///
+/// ```
+/// use algebra::DivisionRing;
+/// use math::modular::Zn;
+///
+/// let x = Zn::<7>::new(3);
+/// assert_eq!(x.rec(), Some(Zn::new(5)));
+/// assert_eq!((x * x).to_string(), "2 (mod 7)");
+/// ```
+///
+/// Calling [`DivisionRing::rec`](algebra::DivisionRing::rec) or
+/// [`DivisionRing::div`](algebra::DivisionRing::div) with a composite modulus
+/// is a compile-time error.
+///
+/// ```compile_fail
+/// use algebra::DivisionRing;
+/// use math::modular::Zn;
+///
+/// Zn::<6>::new(5).rec();
+/// ```
+///
+/// ```compile_fail
+/// use algebra::DivisionRing;
+/// use math::modular::Zn;
+///
+/// Zn::<6>::new(5).div(&Zn::new(1));
+/// ```
+#[algebraic_structure(
+    Ring, Magma<Sub>, Commutative<Mul>,
+    Alternative<Add>, Alternative<Mul>, Medial<Add>, Medial<Mul>,
+    LeftBolLoop<Add>, RightBolLoop<Add>, AutomorphicInverseProperty<Add>;
+    samples = (0..12).map(Zn::<12>::new)
+)]
+#[bin_op(Add, Sub, Mul)]
+#[un_op(Neg)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Zn<const M: u64>(u64);
+
+impl<const M: u64> Zn<M> {
+    /// Evaluates to an error if the modulus is zero.
+    const ASSERT_POSITIVE: () = assert!(M > 0, "the modulus must be positive");
+
+    /// Evaluates to an error if the modulus isn't prime.
+    const ASSERT_PRIME: () = assert!(is_prime(M), "the modulus must be prime");
+
+    /// Returns the residue of an integer.
+    pub const fn new(x: u64) -> Self {
+        let () = Self::ASSERT_POSITIVE;
+        Self(x % M)
+    }
+
+    /// Returns the residue of a signed integer.
+    pub const fn from_i64(x: i64) -> Self {
+        let () = Self::ASSERT_POSITIVE;
+        Self((x as i128).rem_euclid(M as i128) as u64)
+    }
+
+    /// Returns the least non-negative residue.
+    pub const fn get(self) -> u64 {
+        self.0
+    }
+
+    /// Raises the residue to a power.
+    pub const fn pow(self, e: u64) -> Self {
+        Self(pow_mod(self.0, e, M))
+    }
+}
+
+impl<const M: u64> Add for Zn<M> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(add_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: u64> Sub for Zn<M> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(sub_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: u64> Mul for Zn<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(mul_mod(self.0, rhs.0, M))
+    }
+}
+
+impl<const M: u64> Neg for Zn<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(neg_mod(self.0, M))
+    }
+}
+
+impl<const M: u64> UnOp<Rec> for Zn<M> {
+    type Output = Self;
+    type Err = ();
+
+    /// Returns an error for residues that aren't units.
+    fn un_op(&self) -> Result<Self, ()> {
+        inv_mod(self.0, M).map(Self).ok_or(())
+    }
+}
+
+impl<const M: u64> UnOpAssign<Rec> for Zn<M> {
+    type Err = ();
+
+    fn un_op_assign(&mut self) -> Result<(), ()> {
+        *self = UnOp::<Rec>::un_op(self)?;
+        Ok(())
+    }
+}
+
+impl<const M: u64> LeftUnital<algebra::Add> for Zn<M> {
+    fn left_id() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const M: u64> RightUnital<algebra::Add> for Zn<M> {
+    fn right_id() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<const M: u64> LeftUnital<algebra::Mul> for Zn<M> {
+    fn left_id() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> RightUnital<algebra::Mul> for Zn<M> {
+    fn right_id() -> Self {
+        Self::new(1)
+    }
+}
+
+impl<const M: u64> LeftQuasigroup<algebra::Add> for Zn<M> {
+    type LInv = Bws<algebra::Sub>;
+}
+
+impl<const M: u64> RightQuasigroup<algebra::Add> for Zn<M> {
+    type RInv = algebra::Sub;
+}
+
+impl<const M: u64> Group<algebra::Add> for Zn<M> {
+    type Inv = algebra::Neg;
+}
+
+/// The integers modulo a prime form a field. This is implemented for every
+/// modulus, but [`DivisionRing::rec`], and every method built on it, fails to
+/// compile when the modulus is composite.
+impl<const M: u64> DivisionRing for Zn<M> {
+    fn rec(&self) -> Option<Self> {
+        let () = Self::ASSERT_PRIME;
+        UnOp::<Rec>::un_op(self).ok()
+    }
+}
+
+impl<const M: u64> Finite for Zn<M> {
+    fn order() -> usize {
+        M as usize
+    }
+
+    fn elements() -> impl Iterator<Item = Self> {
+        (0..M).map(Self)
+    }
+}
+
+impl<const M: u64> Display for Zn<M> {
+    /// Writes the residue as `x (mod M)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.0, M)
+    }
+}
+
+/// A unit modulo a positive constant `M`, that is, a residue coprime to it.
+///
+/// These form the multiplicative group of [`Zn<M>`], on which taking
+/// reciprocals is total. Unlike on [`Zn`], [`Rec`] is thus an [`Injective`]
+/// operation, which is its own inverse.
+///
+/// ```
+/// use algebra::{Endomorphism, Group, Mul, Rec};
+/// use math::modular::{Zn, ZnUnit};
+///
+/// let x = ZnUnit::new(Zn::<12>::new(5)).unwrap();
+/// assert_eq!(Endomorphism::<Rec>::op(&x), x);
+/// assert_eq!(Group::<Mul>::inv(&x).to_string(), "5 (mod 12)");
+/// assert_eq!(ZnUnit::new(Zn::<12>::new(4)), None);
+/// ```
+#[algebraic_structure(
+    Group<Mul>, Commutative<Mul>, Magma<Div>, Medial<Mul>, Alternative<Mul>,
+    LeftBolLoop<Mul>, RightBolLoop<Mul>, AutomorphicInverseProperty<Mul>;
+    samples = Zn::<15>::elements().filter_map(ZnUnit::new)
+)]
+#[bin_op(Mul, Div)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ZnUnit<const M: u64>(Zn<M>);
+
+impl<const M: u64> ZnUnit<M> {
+    /// Returns a residue as a unit, or `None` if it isn't coprime to `M`.
+    pub fn new(x: Zn<M>) -> Option<Self> {
+        (gcd(x.0, M) == 1).then_some(Self(x))
+    }
+
+    /// Returns the unit as a residue.
+    pub const fn get(self) -> Zn<M> {
+        self.0
+    }
+
+    /// Raises the unit to a power.
+    pub const fn pow(self, e: u64) -> Self {
+        Self(self.0.pow(e))
+    }
+
+    /// Returns the reciprocal of the unit.
+    fn rec(self) -> Self {
+        // Units are coprime to the modulus, so this never fails.
+        Self(Zn(inv_mod(self.0 .0, M).unwrap()))
+    }
+}
+
+impl<const M: u64> Default for ZnUnit<M> {
+    fn default() -> Self {
+        Self(Zn::new(1))
+    }
+}
+
+impl<const M: u64> From<ZnUnit<M>> for Zn<M> {
+    fn from(x: ZnUnit<M>) -> Self {
+        x.0
+    }
+}
+
+impl<const M: u64> Mul for ZnUnit<M> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self(self.0 * rhs.0)
+    }
+}
+
+impl<const M: u64> Div for ZnUnit<M> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self(Zn(mul_mod(self.0 .0, rhs.rec().0 .0, M)))
+    }
+}
+
+impl<const M: u64> UnOp<Rec> for ZnUnit<M> {
+    type Output = Self;
+    type Err = ();
+
+    fn un_op(&self) -> Result<Self, ()> {
+        Ok(self.rec())
+    }
+}
+
+impl<const M: u64> UnOpAssign<Rec> for ZnUnit<M> {
+    type Err = ();
+
+    fn un_op_assign(&mut self) -> Result<(), ()> {
+        *self = self.rec();
+        Ok(())
+    }
+}
+
+impl<const M: u64> Endomorphism<Rec> for ZnUnit<M> {}
+
+impl<const M: u64> Injective<Rec> for ZnUnit<M> {
+    type Inv = Rec;
+}
+
+impl<const M: u64> LeftUnital<algebra::Mul> for ZnUnit<M> {
+    fn left_id() -> Self {
+        Self::default()
+    }
+}
+
+impl<const M: u64> RightUnital<algebra::Mul> for ZnUnit<M> {
+    fn right_id() -> Self {
+        Self::default()
+    }
+}
+
+impl<const M: u64> LeftQuasigroup<algebra::Mul> for ZnUnit<M> {
+    type LInv = Bws<algebra::Div>;
+}
+
+impl<const M: u64> RightQuasigroup<algebra::Mul> for ZnUnit<M> {
+    type RInv = algebra::Div;
+}
+
+impl<const M: u64> Group<algebra::Mul> for ZnUnit<M> {
+    type Inv = Rec;
+}
+
+impl<const M: u64> Display for ZnUnit<M> {
+    /// Writes the unit as `x (mod M)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An integer modulo some positive modulus chosen at runtime, stored together
+/// with it as its least non-negative residue.
+///
+/// Operating on residues with different moduli panics, while the `checked_*`
+/// methods return `None` instead. Since the identities depend on the modulus,
+/// this type isn't [unital](algebra::Unital), and thus neither a group nor a
+/// monoid. Use [`Zn`] if the modulus is known at compile time.
+///
+/// ```
+/// use algebra::{Magma, Mul, Rec, UnOp};
+/// use math::modular::ZnDyn;
+///
+/// let x = ZnDyn::new(4, 9);
+/// assert_eq!(Magma::<Mul>::op(&x, &x).to_string(), "7 (mod 9)");
+/// assert_eq!(x.checked_add(ZnDyn::new(4, 10)), None);
+/// assert_eq!(UnOp::<Rec>::un_op(&x), Ok(ZnDyn::new(7, 9)));
+/// assert_eq!(UnOp::<Rec>::un_op(&ZnDyn::new(3, 9)), Err(()));
+/// ```
+#[algebraic_structure(
+    Magma<Sub>, Associative<Add>, Commutative<Add>, Quasigroup<Add>,
+    Associative<Mul>, Commutative<Mul>,
+    Alternative<Add>, Alternative<Mul>, Medial<Add>, Medial<Mul>;
+    samples = (0..12).map(|x| ZnDyn::new(x, 12))
+)]
+#[bin_op(Add, Sub, Mul)]
+#[un_op(Neg)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ZnDyn {
+    /// The least non-negative residue.
+    value: u64,
+
+    /// The modulus.
+    modulus: u64,
+}
+
+impl ZnDyn {
+    /// Returns the residue of an integer modulo another.
+    ///
+    /// # Panics
+    /// This method will panic if the modulus is zero.
+    pub fn new(x: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "the modulus must be positive");
+        Self {
+            value: x % modulus,
+            modulus,
+        }
+    }
+
+    /// Returns the least non-negative residue.
+    pub fn get(self) -> u64 {
+        self.value
+    }
+
+    /// Returns the modulus.
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns every residue modulo a given modulus.
+    ///
+    /// # Panics
+    /// This method will panic if the modulus is zero.
+    pub fn elements(modulus: u64) -> impl Iterator<Item = Self> {
+        assert!(modulus > 0, "the modulus must be positive");
+        (0..modulus).map(move |value| Self { value, modulus })
+    }
+
+    /// Applies a function on the residues if the moduli match.
+    fn zip(self, rhs: Self, f: fn(u64, u64, u64) -> u64) -> Option<Self> {
+        (self.modulus == rhs.modulus).then(|| Self {
+            value: f(self.value, rhs.value, self.modulus),
+            modulus: self.modulus,
+        })
+    }
+
+    /// Adds two residues, or returns `None` if the moduli differ.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.zip(rhs, add_mod)
+    }
+
+    /// Subtracts two residues, or returns `None` if the moduli differ.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.zip(rhs, sub_mod)
+    }
+
+    /// Multiplies two residues, or returns `None` if the moduli differ.
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.zip(rhs, mul_mod)
+    }
+
+    /// Raises the residue to a power.
+    pub fn pow(self, e: u64) -> Self {
+        Self {
+            value: pow_mod(self.value, e, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl<const M: u64> From<Zn<M>> for ZnDyn {
+    fn from(x: Zn<M>) -> Self {
+        Self::new(x.0, M)
+    }
+}
+
+impl Add for ZnDyn {
+    type Output = Self;
+
+    /// # Panics
+    /// This method will panic if the moduli differ.
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs).expect("the moduli must match")
+    }
+}
+
+impl Sub for ZnDyn {
+    type Output = Self;
+
+    /// # Panics
+    /// This method will panic if the moduli differ.
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs).expect("the moduli must match")
+    }
+}
+
+impl Mul for ZnDyn {
+    type Output = Self;
+
+    /// # Panics
+    /// This method will panic if the moduli differ.
+    fn mul(self, rhs: Self) -> Self {
+        self.checked_mul(rhs).expect("the moduli must match")
+    }
+}
+
+impl Neg for ZnDyn {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            value: neg_mod(self.value, self.modulus),
+            modulus: self.modulus,
+        }
+    }
+}
+
+impl UnOp<Rec> for ZnDyn {
+    type Output = Self;
+    type Err = ();
+
+    /// Returns an error for residues that aren't units.
+    fn un_op(&self) -> Result<Self, ()> {
+        let value = inv_mod(self.value, self.modulus).ok_or(())?;
+        Ok(Self {
+            value,
+            modulus: self.modulus,
+        })
+    }
+}
+
+impl UnOpAssign<Rec> for ZnDyn {
+    type Err = ();
+
+    fn un_op_assign(&mut self) -> Result<(), ()> {
+        *self = UnOp::<Rec>::un_op(self)?;
+        Ok(())
+    }
+}
+
+impl LeftQuasigroup<algebra::Add> for ZnDyn {
+    type LInv = Bws<algebra::Sub>;
+}
+
+impl RightQuasigroup<algebra::Add> for ZnDyn {
+    type RInv = algebra::Sub;
+}
+
+impl Display for ZnDyn {
+    /// Writes the residue as `x (mod m)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::{Field, Magma};
+
+    /// Asserts that a type is a field.
+    fn assert_field<T: Field>() {}
+
+    #[test]
+    fn primes() {
+        let primes: Vec<_> = (0..50).filter(|&n| is_prime(n)).collect();
+        assert_eq!(
+            primes,
+            [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47]
+        );
+
+        // A Carmichael number and a strong pseudoprime to the first few bases.
+        assert!(!is_prime(561) && !is_prime(3_215_031_751));
+        assert!(is_prime((1 << 61) - 1) && is_prime(u64::MAX - 58));
+        assert!(!is_prime(u64::MAX));
+    }
+
+    #[test]
+    fn units() {
+        let units: Vec<_> = Zn::<12>::elements()
+            .filter_map(|x| UnOp::<Rec>::un_op(&x).ok())
+            .collect();
+        assert_eq!(units, [1, 5, 7, 11].map(Zn::new));
+
+        for x in &units {
+            let rec = UnOp::<Rec>::un_op(x).unwrap();
+            assert_eq!(UnOp::<Rec>::un_op(&rec), Ok(*x));
+        }
+
+        let units: Vec<_> = Zn::<12>::elements().filter_map(ZnUnit::new).collect();
+        for x in &units {
+            let rec = Endomorphism::<Rec>::op(x);
+            assert_eq!(Injective::<Rec>::partial_inv(&rec), Ok(*x));
+            assert_eq!(*x * rec, ZnUnit::default());
+            assert_eq!(rec / *x, rec.pow(2));
+        }
+
+        assert_eq!(inv_mod(3, 1), Some(0));
+        assert_eq!(inv_mod(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+    }
+
+    #[test]
+    fn field() {
+        assert_field::<Zn<7>>();
+        for x in Zn::<7>::elements() {
+            assert!(x.test_rec());
+        }
+
+        // The largest 64-bit prime.
+        type F = Zn<{ u64::MAX - 58 }>;
+        let x = F::from_i64(-2);
+        assert_eq!(x * x, F::new(4));
+        assert_eq!(x + F::new(3), F::new(1));
+        assert_eq!(x.div(&x), Some(F::new(1)));
+        assert_eq!(x.pow(u64::MAX - 59), F::new(1));
+    }
+
+    #[test]
+    fn dynamic() {
+        let (x, y) = (ZnDyn::new(5, 12), ZnDyn::new(5, 13));
+        assert_eq!(x.checked_add(y), None);
+        assert_eq!(x * x, ZnDyn::new(1, 12));
+        assert_eq!(Magma::<algebra::Mul>::op(&x, &x), ZnDyn::new(1, 12));
+        assert_eq!(ZnDyn::from(Zn::<12>::new(5)), x);
+        assert_eq!(ZnDyn::elements(12).count(), 12);
+        assert_eq!(x.to_string(), "5 (mod 12)");
+    }
+
+    #[test]
+    #[should_panic]
+    fn dynamic_mismatch() {
+        let _ = Magma::<algebra::Add>::op(&ZnDyn::new(5, 12), &ZnDyn::new(5, 13));
+    }
+}
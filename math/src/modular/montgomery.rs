@@ -0,0 +1,337 @@
+//! Implements [Montgomery multiplication](https://en.wikipedia.org/wiki/Montgomery_modular_multiplication)
+//! for integers modulo an odd constant.
+//!
+//! A residue `x` modulo `M` is stored in Montgomery form `xR mod M`, where `R`
+//! is two to the number of bits in a word. Addition works on this form as
+//! usual, while products are reduced through a few word multiplications
+//! instead of a division. Converting in and out of this form costs a
+//! multiplication each, so this pays off for long chains of operations, such
+//! as the exponentiations of [`PowAssociative::pow_p`](algebra::PowAssociative::pow_p).
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use algebra::{
+    algebraic_structure, bin_op, un_op, Bws, Group, LeftQuasigroup, LeftUnital, RightQuasigroup,
+    RightUnital,
+};
+
+use super::{mul_wide_u128, mul_wide_u64, Zn};
+
+/// Declares a type for integers modulo an odd constant in Montgomery form,
+/// for a given word type and a function returning the full product of two
+/// words.
+macro_rules! impl_montgomery {
+    ($(#[$attr:meta])* $name:ident: $word:ty, $mul_wide:ident; samples = $samples:expr) => {
+        $(#[$attr])*
+        #[algebraic_structure(
+            Ring, Magma<Sub>, Commutative<Mul>;
+            samples = $samples
+        )]
+        #[bin_op(Add, Sub, Mul)]
+        #[un_op(Neg)]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub struct $name<const M: $word>($word);
+
+        impl<const M: $word> $name<M> {
+            /// Evaluates to an error if the modulus isn't odd.
+            const ASSERT_ODD: () = assert!(M % 2 == 1, "the modulus must be odd");
+
+            /// The value `-M⁻¹ mod R`. Every odd number is its own inverse
+            /// modulo 8, and each step of Newton's iteration doubles the
+            /// number of correct bits.
+            const NEG_INV: $word = {
+                let mut inv = M;
+                while M.wrapping_mul(inv) != 1 {
+                    inv = inv.wrapping_mul((2 as $word).wrapping_sub(M.wrapping_mul(inv)));
+                }
+
+                inv.wrapping_neg()
+            };
+
+            /// The value `R² mod M`, found by doubling `R mod M` once for each
+            /// bit in a word.
+            const R2: $word = {
+                let mut r = M.wrapping_neg() % M;
+                let mut i = 0;
+                while i < <$word>::BITS {
+                    r = Self::add_raw(r, r);
+                    i += 1;
+                }
+
+                r
+            };
+
+            /// Returns `a + b mod M`, for `a, b < M`.
+            const fn add_raw(a: $word, b: $word) -> $word {
+                let (sum, overflow) = a.overflowing_add(b);
+                if overflow || sum >= M {
+                    sum.wrapping_sub(M)
+                } else {
+                    sum
+                }
+            }
+
+            /// Returns `xR⁻¹ mod M`, for `x = hi R + lo < MR`. This is
+            /// Montgomery's REDC algorithm.
+            const fn redc(hi: $word, lo: $word) -> $word {
+                let t = lo.wrapping_mul(Self::NEG_INV);
+                let (t_hi, _) = $mul_wide(t, M);
+
+                // The low word of x + tM is zero, so the sum carries exactly
+                // when the low word of x isn't.
+                let (sum, overflow_hi) = hi.overflowing_add(t_hi);
+                let (sum, overflow_lo) = sum.overflowing_add((lo != 0) as $word);
+                if overflow_hi || overflow_lo || sum >= M {
+                    sum.wrapping_sub(M)
+                } else {
+                    sum
+                }
+            }
+
+            /// Converts an integer into Montgomery form.
+            pub const fn new(x: $word) -> Self {
+                let () = Self::ASSERT_ODD;
+                let (hi, lo) = $mul_wide(x % M, Self::R2);
+                Self(Self::redc(hi, lo))
+            }
+
+            /// Wraps a value that's already in Montgomery form, reducing it
+            /// modulo `M`.
+            pub const fn from_montgomery(x: $word) -> Self {
+                let () = Self::ASSERT_ODD;
+                Self(x % M)
+            }
+
+            /// Returns the Montgomery form `xR mod M`.
+            pub const fn montgomery(self) -> $word {
+                self.0
+            }
+
+            /// Converts out of Montgomery form, returning the least
+            /// non-negative residue.
+            pub const fn get(self) -> $word {
+                Self::redc(0, self.0)
+            }
+        }
+
+        impl<const M: $word> Add for $name<M> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self(Self::add_raw(self.0, rhs.0))
+            }
+        }
+
+        impl<const M: $word> Sub for $name<M> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self(Self::add_raw(self.0, (-rhs).0))
+            }
+        }
+
+        impl<const M: $word> Mul for $name<M> {
+            type Output = Self;
+
+            fn mul(self, rhs: Self) -> Self {
+                let (hi, lo) = $mul_wide(self.0, rhs.0);
+                Self(Self::redc(hi, lo))
+            }
+        }
+
+        impl<const M: $word> Neg for $name<M> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(if self.0 == 0 { 0 } else { M - self.0 })
+            }
+        }
+
+        impl<const M: $word> LeftUnital<algebra::Add> for $name<M> {
+            fn left_id() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl<const M: $word> RightUnital<algebra::Add> for $name<M> {
+            fn right_id() -> Self {
+                Self::new(0)
+            }
+        }
+
+        impl<const M: $word> LeftUnital<algebra::Mul> for $name<M> {
+            fn left_id() -> Self {
+                Self::new(1)
+            }
+        }
+
+        impl<const M: $word> RightUnital<algebra::Mul> for $name<M> {
+            fn right_id() -> Self {
+                Self::new(1)
+            }
+        }
+
+        impl<const M: $word> LeftQuasigroup<algebra::Add> for $name<M> {
+            type LInv = Bws<algebra::Sub>;
+        }
+
+        impl<const M: $word> RightQuasigroup<algebra::Add> for $name<M> {
+            type RInv = algebra::Sub;
+        }
+
+        impl<const M: $word> Group<algebra::Add> for $name<M> {
+            type Inv = algebra::Neg;
+        }
+
+        impl<const M: $word> Display for $name<M> {
+            /// Writes the residue as `x (mod M)`.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{} (mod {})", self.get(), M)
+            }
+        }
+    };
+}
+
+impl_montgomery!(
+    /// An integer modulo an odd 64-bit constant `M`, stored in Montgomery
+    /// form. This is a drop-in replacement for [`Zn`] with faster
+    /// multiplication.
+    ///
+    /// ```
+    /// use algebra::{Mul, PowAssociative};
+    /// use math::modular::montgomery::Montgomery;
+    ///
+    /// type F = Montgomery<1_000_000_007>;
+    /// let mut x = F::new(2);
+    /// PowAssociative::<Mul>::pow_p(&mut x, 1_000_000_006u128);
+    /// assert_eq!(x, F::new(1));
+    /// assert_eq!(F::new(5).get(), 5);
+    /// ```
+    ///
+    /// Using an even modulus is a compile-time error.
+    ///
+    /// ```compile_fail
+    /// math::modular::montgomery::Montgomery::<12>::new(5);
+    /// ```
+    Montgomery: u64, mul_wide_u64;
+    samples = (0..15).map(Montgomery::<15>::new)
+);
+
+impl_montgomery!(
+    /// An integer modulo an odd 128-bit constant `M`, stored in Montgomery
+    /// form.
+    ///
+    /// ```
+    /// use math::modular::montgomery::Montgomery128;
+    ///
+    /// // The Mersenne prime 2¹²⁷ - 1.
+    /// type F = Montgomery128<{ (1 << 127) - 1 }>;
+    /// let x = F::new(1 << 100);
+    /// assert_eq!((x * x).get(), 1 << 73);
+    /// ```
+    Montgomery128: u128, mul_wide_u128;
+    samples = (0..15).map(Montgomery128::<15>::new)
+);
+
+impl<const M: u64> From<Zn<M>> for Montgomery<M> {
+    fn from(x: Zn<M>) -> Self {
+        Self::new(x.get())
+    }
+}
+
+impl<const M: u64> From<Montgomery<M>> for Zn<M> {
+    fn from(x: Montgomery<M>) -> Self {
+        Self::new(x.get())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use algebra::PowAssociative;
+
+    /// Returns `ab mod m` by doubling and adding, one bit at a time.
+    fn naive_mul(a: u128, b: u128, m: u128) -> u128 {
+        let add = |a: u128, b: u128| {
+            let (sum, overflow) = a.overflowing_add(b);
+            if overflow || sum >= m {
+                sum.wrapping_sub(m)
+            } else {
+                sum
+            }
+        };
+
+        (0..128).rev().fold(0, |res, i| {
+            let res = add(res, res);
+            if (b >> i) & 1 == 1 {
+                add(res, a % m)
+            } else {
+                res
+            }
+        })
+    }
+
+    /// Returns a random 128-bit integer.
+    fn next_u128(rng: &mut Rng) -> u128 {
+        (rng.next_u64() as u128) << 64 | rng.next_u64() as u128
+    }
+
+    #[test]
+    fn montgomery() {
+        const P: u64 = u64::MAX - 58;
+        let mut rng = Rng::new(49);
+
+        for _ in 0..200 {
+            let (a, b) = (rng.next_u64(), rng.next_u64());
+            let (x, y) = (Montgomery::<P>::new(a), Montgomery::<P>::new(b));
+            let (zx, zy) = (Zn::<P>::new(a), Zn::<P>::new(b));
+
+            assert_eq!(x.get(), zx.get());
+            assert_eq!(Zn::from(x * y), zx * zy);
+            assert_eq!(Zn::from(x + y), zx + zy);
+            assert_eq!(Zn::from(x - y), zx - zy);
+            assert_eq!(Montgomery::from(zx), x);
+            assert_eq!(Montgomery::<P>::from_montgomery(x.montgomery()), x);
+
+            let e = rng.next_u64();
+            let mut pow = x;
+            PowAssociative::<algebra::Mul>::pow_p(&mut pow, e as u128);
+            assert_eq!(Zn::from(pow), zx.pow(e));
+        }
+
+        assert_eq!(Montgomery::<1>::new(5).get(), 0);
+        assert_eq!(
+            Montgomery::<{ u64::MAX }>::new(u64::MAX - 1).get(),
+            u64::MAX - 1
+        );
+    }
+
+    #[test]
+    fn montgomery_128() {
+        const P: u128 = u128::MAX - 158;
+        const Q: u128 = (1 << 127) - 1;
+        let mut rng = Rng::new(128);
+
+        for _ in 0..200 {
+            let (a, b) = (next_u128(&mut rng), next_u128(&mut rng));
+            let (x, y) = (Montgomery128::<P>::new(a), Montgomery128::<P>::new(b));
+            assert_eq!(x.get(), a % P);
+            assert_eq!((x * y).get(), naive_mul(a, b, P));
+            let (a, b) = (a % P, b % P);
+            let diff = if a >= b { a - b } else { P - (b - a) };
+            assert_eq!((x - y).get(), diff);
+
+            let (x, y) = (Montgomery128::<Q>::new(a), Montgomery128::<Q>::new(b));
+            assert_eq!((x * y).get(), naive_mul(a, b, Q));
+        }
+
+        // Fermat's little theorem.
+        let mut x = Montgomery128::<P>::new(3);
+        PowAssociative::<algebra::Mul>::pow_p(&mut x, P - 1);
+        assert_eq!(x.get(), 1);
+    }
+}
@@ -0,0 +1,279 @@
+//! Implements [residue number systems](https://en.wikipedia.org/wiki/Residue_number_system),
+//! which represent large integers through their residues modulo several
+//! pairwise coprime moduli.
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, Mul, Neg, Sub},
+};
+
+use algebra::{algebraic_structure, bin_op, un_op, Bws, LeftQuasigroup, RightQuasigroup};
+
+use super::{add_mod, crt, gcd, mul_mod, neg_mod, pow_mod, sub_mod};
+use crate::{
+    bignat::BigNat,
+    storage::{ArrayStorageE, OwnedStorage, VecStorageE},
+};
+
+/// An integer modulo the product of some pairwise coprime moduli, stored as
+/// its residues modulo each of them.
+///
+/// By the [Chinese remainder theorem](crt::crt), ring operations work
+/// componentwise on the residues, which makes them cheap and parallelizable.
+/// Converting back into a [`BigNat`] uses [Garner's algorithm](crt::garner).
+///
+/// Operating on numbers with different moduli panics, while the `checked_*`
+/// methods return `None` instead. As with [`ZnDyn`](super::ZnDyn), this type
+/// isn't [unital](algebra::Unital), since the identities depend on the moduli.
+///
+/// ```
+/// use math::{bignat::BigNat, modular::rns::RnsS};
+///
+/// let moduli = [u64::MAX, u64::MAX - 1, (1 << 61) - 1].into();
+/// let n = BigNat::from(u64::MAX as u128 + 2);
+/// let x = RnsS::from_bignat(&n, moduli).unwrap();
+/// assert_eq!((&x * &x).to_bignat(), &n * &n);
+/// ```
+#[algebraic_structure(
+    Magma<Sub>, Associative<Add>, Commutative<Add>, Quasigroup<Add>,
+    Associative<Mul>, Commutative<Mul>,
+    Alternative<Add>, Alternative<Mul>, Medial<Add>, Medial<Mul>;
+    samples = (0..12u64).map(|x| RnsS::from_bignat(&BigNat::from(x), [3, 4].into()).unwrap())
+)]
+#[bin_op(Add, Sub, Mul, by_ref)]
+#[un_op(Neg, by_ref)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rns<S: OwnedStorage<Inner = u64>> {
+    /// The least non-negative residues.
+    residues: S,
+
+    /// The pairwise coprime moduli.
+    moduli: S,
+}
+
+/// A statically-sized residue number system, backed by an
+/// [`ArrayStorage`](crate::storage::ArrayStorage).
+pub type RnsS<const N: usize> = Rns<ArrayStorageE<u64, N>>;
+
+/// A dynamically-sized residue number system, backed by a
+/// [`VecStorage`](crate::storage::VecStorage).
+pub type RnsD = Rns<VecStorageE<u64>>;
+
+impl<S: OwnedStorage<Inner = u64>> Rns<S> {
+    /// Returns whether the moduli are positive and pairwise coprime.
+    fn valid_moduli(moduli: &S) -> bool {
+        (0..moduli.len()).all(|i| moduli[i] > 0 && (0..i).all(|j| gcd(moduli[i], moduli[j]) == 1))
+    }
+
+    /// Initializes a number from its residues and moduli, or returns `None` if
+    /// the lengths differ, the moduli aren't positive and pairwise coprime, or
+    /// the residues aren't reduced.
+    pub fn new(residues: S, moduli: S) -> Option<Self> {
+        (residues.len() == moduli.len()
+            && Self::valid_moduli(&moduli)
+            && residues.iter().zip(moduli.iter()).all(|(r, m)| r < m))
+        .then_some(Self { residues, moduli })
+    }
+
+    /// Returns the residues of a number, or `None` if the moduli aren't
+    /// positive and pairwise coprime.
+    pub fn from_bignat(x: &BigNat, moduli: S) -> Option<Self> {
+        Self::valid_moduli(&moduli).then(|| Self {
+            residues: moduli.iter().map(|&m| x.rem_small(m)).collect(),
+            moduli,
+        })
+    }
+
+    /// Returns the residues.
+    pub fn residues(&self) -> &S {
+        &self.residues
+    }
+
+    /// Returns the moduli.
+    pub fn moduli(&self) -> &S {
+        &self.moduli
+    }
+
+    /// Returns the product of the moduli, which the number is taken modulo.
+    pub fn modulus(&self) -> BigNat {
+        self.moduli.iter().fold(BigNat::one(), |mut prod, &m| {
+            prod.mul_small(m);
+            prod
+        })
+    }
+
+    /// Returns the least non-negative integer with the given residues.
+    pub fn to_bignat(&self) -> BigNat {
+        let residues: Vec<_> = self.residues.iter().copied().collect();
+        let moduli: Vec<_> = self.moduli.iter().copied().collect();
+        let digits = crt::garner(&residues, &moduli).expect("the moduli should be coprime");
+        crt::from_mixed_radix(&digits, &moduli)
+    }
+
+    /// Returns a copy of the moduli.
+    fn clone_moduli(&self) -> S {
+        self.moduli.iter().copied().collect()
+    }
+
+    /// Applies a function on the residues if the moduli match.
+    fn zip(&self, rhs: &Self, f: fn(u64, u64, u64) -> u64) -> Option<Self> {
+        self.moduli.iter().eq(rhs.moduli.iter()).then(|| Self {
+            residues: (0..self.moduli.len())
+                .map(|i| f(self.residues[i], rhs.residues[i], self.moduli[i]))
+                .collect(),
+            moduli: self.clone_moduli(),
+        })
+    }
+
+    /// Adds two numbers, or returns `None` if the moduli differ.
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        self.zip(rhs, add_mod)
+    }
+
+    /// Subtracts two numbers, or returns `None` if the moduli differ.
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        self.zip(rhs, sub_mod)
+    }
+
+    /// Multiplies two numbers, or returns `None` if the moduli differ.
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        self.zip(rhs, mul_mod)
+    }
+
+    /// Raises the number to a power.
+    pub fn pow(&self, e: u64) -> Self {
+        Self {
+            residues: self
+                .residues
+                .iter()
+                .zip(self.moduli.iter())
+                .map(|(&r, &m)| pow_mod(r, e, m))
+                .collect(),
+            moduli: self.clone_moduli(),
+        }
+    }
+}
+
+/// Implements an operation on references to numbers in terms of its
+/// `checked_*` method.
+macro_rules! impl_rns_op {
+    ($tr:ident, $method:ident, $checked:ident) => {
+        impl<S: OwnedStorage<Inner = u64>> $tr for &Rns<S> {
+            type Output = Rns<S>;
+
+            /// # Panics
+            /// This method will panic if the moduli differ.
+            fn $method(self, rhs: Self) -> Rns<S> {
+                self.$checked(rhs).expect("the moduli must match")
+            }
+        }
+    };
+}
+
+impl_rns_op!(Add, add, checked_add);
+impl_rns_op!(Sub, sub, checked_sub);
+impl_rns_op!(Mul, mul, checked_mul);
+
+impl<S: OwnedStorage<Inner = u64>> Neg for &Rns<S> {
+    type Output = Rns<S>;
+
+    fn neg(self) -> Rns<S> {
+        Rns {
+            residues: self
+                .residues
+                .iter()
+                .zip(self.moduli.iter())
+                .map(|(&r, &m)| neg_mod(r, m))
+                .collect(),
+            moduli: self.clone_moduli(),
+        }
+    }
+}
+
+impl<S: OwnedStorage<Inner = u64>> LeftQuasigroup<algebra::Add> for Rns<S> {
+    type LInv = Bws<algebra::Sub>;
+}
+
+impl<S: OwnedStorage<Inner = u64>> RightQuasigroup<algebra::Add> for Rns<S> {
+    type RInv = algebra::Sub;
+}
+
+impl<S: OwnedStorage<Inner = u64>> Display for Rns<S> {
+    /// Writes the number as `x (mod m)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.to_bignat(), self.modulus())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::Rng;
+    use algebra::Magma;
+
+    #[test]
+    fn rns() {
+        let moduli: Vec<u64> = vec![u64::MAX, u64::MAX - 1, (1 << 61) - 1, 1_000_000_007];
+        let storage = || moduli.iter().copied().collect::<VecStorageE<u64>>();
+        let mut rng = Rng::new(50);
+        let mut random = || {
+            let (hi, lo) = (rng.next_u64() as u128, rng.next_u64() as u128);
+            BigNat::from(hi << 64 | lo)
+        };
+
+        for _ in 0..50 {
+            let (a, b) = (random(), random());
+            let x = RnsD::from_bignat(&a, storage()).unwrap();
+            let y = RnsD::from_bignat(&b, storage()).unwrap();
+            let modulus = x.modulus();
+            let reduce = |n: BigNat| n.div_rem(&modulus).1;
+
+            assert_eq!(x.to_bignat(), a);
+            assert_eq!(Magma::<algebra::Add>::op(&x, &y).to_bignat(), &a + &b);
+            assert_eq!(
+                Magma::<algebra::Mul>::op(&x, &y).to_bignat(),
+                reduce(&a * &b)
+            );
+
+            let mut neg_b = modulus.clone();
+            neg_b.sub_assign(&b);
+            assert_eq!((-&y).to_bignat(), neg_b);
+            assert_eq!(
+                Magma::<algebra::Sub>::op(&x, &y).to_bignat(),
+                reduce(&a + &neg_b)
+            );
+        }
+
+        let x = RnsD::from_bignat(&BigNat::from(7u64), storage()).unwrap();
+        assert_eq!(x.pow(3).to_bignat(), BigNat::from(343u64));
+        assert_eq!(x.pow(0).to_bignat(), BigNat::one());
+    }
+
+    #[test]
+    fn invalid() {
+        let six = BigNat::from(6u64);
+        assert!(RnsS::from_bignat(&six, [4, 6].into()).is_none());
+        assert!(RnsS::from_bignat(&six, [0, 5].into()).is_none());
+        assert!(RnsS::new([3, 1].into(), [3, 5].into()).is_none());
+        assert!(RnsD::new(
+            [1].iter().copied().collect(),
+            [3, 5].iter().copied().collect()
+        )
+        .is_none());
+
+        let x = RnsS::from_bignat(&six, [3, 5].into()).unwrap();
+        let y = RnsS::from_bignat(&six, [5, 3].into()).unwrap();
+        assert_eq!(x.residues(), &[0, 1].into());
+        assert_eq!(x.to_string(), "6 (mod 15)");
+        assert_eq!(x.checked_add(&y), None);
+        assert_eq!(RnsS::new([0, 1].into(), [3, 5].into()), Some(x));
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatch() {
+        let x = RnsS::from_bignat(&BigNat::one(), [3, 5].into()).unwrap();
+        let y = RnsS::from_bignat(&BigNat::one(), [5, 3].into()).unwrap();
+        let _ = Magma::<algebra::Mul>::op(&x, &y);
+    }
+}
@@ -0,0 +1,387 @@
+//! Implements [partial permutations](https://en.wikipedia.org/wiki/Partial_permutation),
+//! which together form the
+//! [symmetric inverse monoid](https://en.wikipedia.org/wiki/Symmetric_inverse_semigroup).
+
+use std::{
+    iter::{self, FromIterator},
+    ops::{Index, Mul},
+};
+
+use algebra::{
+    Associative, BinOp, BinOpAssign, InverseSemigroup, LeftUnital, Magma, PowAssociative,
+    RightUnital,
+};
+
+use crate::{
+    permutation::Permutation,
+    storage::{ArrayStorageE, Iter, OwnedStorage, Size, StackStorage, Storage, VecStorageE},
+};
+
+/// Represents a partial permutation, that is, an injective function from some
+/// subset of the numbers from 0 to its length minus 1 into themselves. This is
+/// stored as some storage of `Option<usize>`, where `None` marks the points
+/// outside of the domain. All other entries must be pairwise distinct, and less
+/// than the length of the partial permutation.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PartialPermutation<S: Storage<Inner = Option<usize>>>(S);
+
+/// A statically-sized partial permutation, backed by an [`ArrayStorage`].
+pub type PartialPermutationS<const N: usize> = PartialPermutation<ArrayStorageE<Option<usize>, N>>;
+
+/// A dynamically-sized partial permutation, backed by an [`VecStorage`].
+pub type PartialPermutationD = PartialPermutation<VecStorageE<Option<usize>>>;
+
+impl<S: OwnedStorage<Inner = Option<usize>>> FromIterator<Option<usize>> for PartialPermutation<S> {
+    fn from_iter<T: IntoIterator<Item = Option<usize>>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<S: Storage<Inner = Option<usize>>> Index<usize> for PartialPermutation<S> {
+    type Output = Option<usize>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<S: Storage<Inner = Option<usize>>> PartialPermutation<S> {
+    /// Returns the length of the partial permutation.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the partial permutation is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the size of the partial permutation.
+    pub fn size(&self) -> Size<S> {
+        self.0.size()
+    }
+
+    /// Returns an iterator over the entries of the partial permutation.
+    pub fn iter(&self) -> iter::Copied<Iter<'_, S>> {
+        self.0.iter().copied()
+    }
+
+    /// Returns the underlying storage of the partial permutation.
+    pub fn into_storage(self) -> S {
+        self.0
+    }
+
+    /// Returns the domain of the partial permutation, in increasing order.
+    pub fn domain(&self) -> Vec<usize> {
+        (0..self.len()).filter(|&i| self[i].is_some()).collect()
+    }
+
+    /// Returns the image of the partial permutation, in increasing order.
+    pub fn image(&self) -> Vec<usize> {
+        let mut in_image = vec![false; self.len()];
+        for v in self.iter().flatten() {
+            in_image[v] = true;
+        }
+
+        (0..self.len()).filter(|&v| in_image[v]).collect()
+    }
+
+    /// Returns the rank of the partial permutation, meaning the size of its
+    /// domain.
+    pub fn rank(&self) -> usize {
+        self.iter().flatten().count()
+    }
+
+    /// Returns whether the partial permutation is defined everywhere, which
+    /// makes it a permutation.
+    pub fn is_total(&self) -> bool {
+        self.iter().all(|v| v.is_some())
+    }
+
+    /// Returns whether the partial permutation is the identity on its domain.
+    /// These are precisely the idempotents of the symmetric inverse monoid.
+    pub fn is_idempotent(&self) -> bool {
+        self.iter()
+            .enumerate()
+            .all(|(i, v)| v.is_none_or(|v| i == v))
+    }
+
+    /// Returns whether the partial permutation is the identity.
+    pub fn is_identity(&self) -> bool {
+        self.iter().enumerate().all(|(i, v)| v == Some(i))
+    }
+
+    /// Converts the partial permutation into a permutation, if it's total.
+    ///
+    /// # Panics
+    /// This method will panic if the length of the partial permutation is
+    /// incompatible with the size of the permutation.
+    pub fn to_permutation<A: OwnedStorage<Inner = usize>>(&self) -> Option<Permutation<A>> {
+        if !self.is_total() {
+            return None;
+        }
+
+        // Safety: a total partial permutation is a permutation.
+        unsafe { Some(Permutation::new_unchecked(self.iter().flatten().collect())) }
+    }
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> PartialPermutation<S> {
+    /// Initializes a new partial permutation. No invariants are checked.
+    ///
+    /// # Safety
+    /// All entries of the partial permutation other than `None` must be
+    /// pairwise distinct integers, all less than its length.
+    pub unsafe fn new_unchecked(s: S) -> Self {
+        Self(s)
+    }
+
+    /// Initializes a new partial permutation. Will check that the entries are
+    /// valid. To forgo this expensive check, use [`new_unchecked`].
+    pub fn new(s: S) -> Option<Self> {
+        // We check that there are no repeat entries, nor entries out of bounds.
+        let mut checked = vec![false; s.len()];
+        for &v in s.iter().flatten() {
+            let entry = checked.get_mut(v)?;
+
+            if *entry {
+                return None;
+            }
+
+            *entry = true;
+        }
+
+        // Safety: we literally just checked the invariants!
+        unsafe { Some(Self::new_unchecked(s)) }
+    }
+
+    /// Returns the identity partial permutation.
+    pub fn identity(size: Size<S>) -> Self {
+        (0..size.value()).map(Some).collect()
+    }
+
+    /// Returns the empty partial permutation, which is defined nowhere.
+    pub fn empty(size: Size<S>) -> Self {
+        iter::repeat_n(None, size.value()).collect()
+    }
+
+    /// Returns the identity on a given set of points, which is undefined
+    /// everywhere else.
+    ///
+    /// # Panics
+    /// This method will panic if any point is out of bounds.
+    pub fn partial_identity(size: Size<S>, set: &[usize]) -> Self {
+        let mut entries = vec![None; size.value()];
+        for &i in set {
+            entries[i] = Some(i);
+        }
+
+        // Safety: a partial identity is injective.
+        unsafe { Self::new_unchecked(entries.into_iter().collect()) }
+    }
+
+    /// Converts a permutation into a partial permutation defined everywhere.
+    pub fn from_permutation<A: Storage<Inner = usize>>(p: &Permutation<A>) -> Self {
+        p.iter().map(Some).collect()
+    }
+
+    /// Composes two partial permutations together, so that the result maps `i`
+    /// to `p[q[i]]`, whenever both are defined.
+    ///
+    /// # Panics
+    /// This method will panic if `p` and `q` have different lengths.
+    pub fn compose<A: Storage<Inner = Option<usize>>, B: Storage<Inner = Option<usize>>>(
+        p: &PartialPermutation<A>,
+        q: &PartialPermutation<B>,
+    ) -> Self {
+        assert_eq!(p.len(), q.len(), "size mismatch");
+        q.iter().map(|v| v.and_then(|v| p[v])).collect()
+    }
+
+    /// Returns the inverse of a partial permutation, which maps its image back
+    /// onto its domain. This is the unique partial permutation `q` with
+    /// `p q p = p` and `q p q = q`.
+    pub fn inv(&self) -> Self {
+        let mut inv = vec![None; self.len()];
+        for (i, v) in self.iter().enumerate() {
+            if let Some(v) = v {
+                inv[v] = Some(i);
+            }
+        }
+
+        // Safety: the inverse of a partial permutation is a partial
+        // permutation.
+        unsafe { Self::new_unchecked(inv.into_iter().collect()) }
+    }
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> BinOp<algebra::Mul> for PartialPermutation<S> {
+    type Output = Self;
+    type Err = ();
+
+    /// Composes two partial permutations, or returns an error if their lengths
+    /// differ.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        if self.len() == rhs.len() {
+            Ok(Self::compose(self, rhs))
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> BinOpAssign<algebra::Mul> for PartialPermutation<S> {
+    type Err = ();
+
+    fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+        *self = BinOp::<algebra::Mul>::bin_op(self, rhs)?;
+        Ok(())
+    }
+
+    fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+        if self.len() != rhs.len() {
+            return Err(());
+        }
+
+        for v in rhs.0.iter_mut() {
+            *v = v.and_then(|v| self[v]);
+        }
+
+        Ok(())
+    }
+}
+
+/// Composing partial permutations of different lengths isn't a "normal"
+/// circumstance.
+impl<S: OwnedStorage<Inner = Option<usize>>> Magma<algebra::Mul> for PartialPermutation<S> {}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> PowAssociative<algebra::Mul>
+    for PartialPermutation<S>
+{
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> Associative<algebra::Mul> for PartialPermutation<S> {}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> InverseSemigroup<algebra::Mul>
+    for PartialPermutation<S>
+{
+    fn gen_inv(&self) -> Self {
+        self.inv()
+    }
+}
+
+impl<S: StackStorage<Inner = Option<usize>>> LeftUnital<algebra::Mul> for PartialPermutation<S> {
+    fn left_id() -> Self {
+        Self::identity(Default::default())
+    }
+}
+
+impl<S: StackStorage<Inner = Option<usize>>> RightUnital<algebra::Mul> for PartialPermutation<S> {
+    fn right_id() -> Self {
+        Self::identity(Default::default())
+    }
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> Mul for PartialPermutation<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a, S: OwnedStorage<Inner = Option<usize>>> Mul<&'a Self> for PartialPermutation<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a Self) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> Mul<PartialPermutation<S>> for &PartialPermutation<S> {
+    type Output = PartialPermutation<S>;
+
+    fn mul(self, rhs: PartialPermutation<S>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<S: OwnedStorage<Inner = Option<usize>>> Mul<&PartialPermutation<S>>
+    for &PartialPermutation<S>
+{
+    type Output = PartialPermutation<S>;
+
+    fn mul(self, rhs: &PartialPermutation<S>) -> Self::Output {
+        PartialPermutation::compose(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permutation::PermutationS;
+
+    #[test]
+    fn structure() {
+        let p = PartialPermutationS::new([Some(3), None, Some(0), None].into()).unwrap();
+        assert_eq!(p.domain(), vec![0, 2]);
+        assert_eq!(p.image(), vec![0, 3]);
+        assert_eq!(p.rank(), 2);
+        assert!(!p.is_total());
+        assert!(PartialPermutationS::new([Some(1), Some(1)].into()).is_none());
+        assert!(PartialPermutationS::new([None, Some(2)].into()).is_none());
+    }
+
+    #[test]
+    fn inverse_semigroup() {
+        let p = PartialPermutationS::new([Some(3), None, Some(0), None].into()).unwrap();
+        let q = PartialPermutationS::new([Some(2), Some(0), None, Some(1)].into()).unwrap();
+        let r = PartialPermutationS::new([None, Some(3), Some(2), Some(1)].into()).unwrap();
+
+        assert_eq!(
+            (p * q).iter().collect::<Vec<_>>(),
+            vec![Some(0), Some(3), None, None]
+        );
+        assert!(Associative::<algebra::Mul>::test_assoc(&p, &q, &r));
+
+        for x in [p, q, r] {
+            assert!(InverseSemigroup::<algebra::Mul>::test_gen_inv(&x));
+            assert!(InverseSemigroup::<algebra::Mul>::test_gen_inv_inv(&x));
+            assert!((x * x.inv()).is_idempotent());
+            assert_eq!(
+                x * x.inv(),
+                PartialPermutation::partial_identity(x.size(), &x.image())
+            );
+        }
+
+        assert!(InverseSemigroup::<algebra::Mul>::test_idempotents_commute(
+            &p, &q
+        ));
+    }
+
+    #[test]
+    fn permutation() {
+        let p = PermutationS::new([2, 0, 1].into()).unwrap();
+        let q = PartialPermutationS::from_permutation(&p);
+        assert!(q.is_total());
+        assert_eq!(q.to_permutation(), Some(p));
+        assert_eq!(
+            q * q.inv(),
+            PartialPermutationS::identity(Default::default())
+        );
+
+        let e = PartialPermutationS::<3>::empty(Default::default());
+        assert_eq!(e.to_permutation::<ArrayStorageE<usize, 3>>(), None);
+        assert_eq!(e * q, e);
+    }
+
+    #[test]
+    #[should_panic]
+    fn compose_size_mismatch() {
+        let p = PartialPermutationD::new(vec![Some(2), Some(0), Some(1)].into_iter().collect())
+            .unwrap();
+        let q = PartialPermutationD::new(vec![Some(0), Some(1)].into_iter().collect()).unwrap();
+        PartialPermutationD::compose(&p, &q);
+    }
+}
@@ -0,0 +1,270 @@
+//! Implements combinatorial statistics and predicates on permutations, such as
+//! [derangements](https://en.wikipedia.org/wiki/Derangement),
+//! [inversions](https://en.wikipedia.org/wiki/Inversion_(discrete_mathematics)),
+//! and [patterns](https://en.wikipedia.org/wiki/Permutation_pattern).
+
+use super::Permutation;
+use crate::{
+    random::Rng,
+    storage::{OwnedStorage, Size, Storage},
+};
+
+impl<S: Storage<Inner = usize>> Permutation<S> {
+    /// Returns the fixed points of the permutation, in increasing order.
+    pub fn fixed_points(&self) -> Vec<usize> {
+        self.iter()
+            .enumerate()
+            .filter_map(|(i, v)| (i == v).then_some(i))
+            .collect()
+    }
+
+    /// Returns whether the permutation is a derangement, meaning it has no
+    /// fixed points.
+    pub fn is_derangement(&self) -> bool {
+        self.iter().enumerate().all(|(i, v)| i != v)
+    }
+
+    /// Returns whether the permutation is an
+    /// [involution](https://en.wikipedia.org/wiki/Involution_(mathematics)),
+    /// meaning it's its own inverse.
+    pub fn is_involution(&self) -> bool {
+        self.iter().all(|v| self[self[v]] == v)
+    }
+
+    /// Returns the descents of the permutation. These are the indices `i` such
+    /// that `p[i] > p[i + 1]`.
+    pub fn descents(&self) -> Vec<usize> {
+        (1..self.len())
+            .filter(|&i| self[i - 1] > self[i])
+            .map(|i| i - 1)
+            .collect()
+    }
+
+    /// Returns the number of inversions of the permutation. These are the
+    /// pairs `i < j` such that `p[i] > p[j]`.
+    ///
+    /// This takes `O(n log n)` time, by counting the inversions while merge
+    /// sorting the entries.
+    pub fn inversions(&self) -> usize {
+        /// Sorts a slice and returns its number of inversions, using `buf` as
+        /// scratch space.
+        fn sort_count(v: &mut [usize], buf: &mut [usize]) -> usize {
+            let n = v.len();
+            if n < 2 {
+                return 0;
+            }
+
+            let mid = n / 2;
+            let mut count = sort_count(&mut v[..mid], &mut buf[..mid])
+                + sort_count(&mut v[mid..], &mut buf[mid..]);
+
+            let (mut i, mut j) = (0, mid);
+            for b in buf[..n].iter_mut() {
+                if j == n || (i < mid && v[i] < v[j]) {
+                    *b = v[i];
+                    i += 1;
+                } else {
+                    // Every remaining entry on the left forms an inversion
+                    // with this one.
+                    *b = v[j];
+                    count += mid - i;
+                    j += 1;
+                }
+            }
+
+            v.copy_from_slice(&buf[..n]);
+            count
+        }
+
+        let mut v: Vec<_> = self.iter().collect();
+        let mut buf = vec![0; v.len()];
+        sort_count(&mut v, &mut buf)
+    }
+
+    /// Returns a [longest increasing subsequence](https://en.wikipedia.org/wiki/Longest_increasing_subsequence)
+    /// of the entries of the permutation. If there are many, the one returned
+    /// ends at the smallest possible entry.
+    ///
+    /// This takes `O(n log n)` time, using patience sorting.
+    pub fn longest_increasing_subsequence(&self) -> Vec<usize> {
+        // The index of the smallest possible tail of an increasing subsequence
+        // of every length.
+        let mut tails: Vec<usize> = Vec::new();
+
+        // The index of the previous entry in the subsequence ending at every
+        // index.
+        let mut prev = vec![None; self.len()];
+
+        for (i, v) in self.iter().enumerate() {
+            let k = tails.partition_point(|&j| self[j] < v);
+            prev[i] = k.checked_sub(1).map(|k| tails[k]);
+
+            if k == tails.len() {
+                tails.push(i);
+            } else {
+                tails[k] = i;
+            }
+        }
+
+        let mut lis = Vec::with_capacity(tails.len());
+        let mut cur = tails.last().copied();
+        while let Some(i) = cur {
+            lis.push(self[i]);
+            cur = prev[i];
+        }
+
+        lis.reverse();
+        lis
+    }
+
+    /// Returns the indices of an occurrence of a pattern in the permutation, if
+    /// any. These are indices `i₀ < i₁ < …` such that the entries `p[i₀],
+    /// p[i₁], …` are in the same relative order as the entries of the pattern.
+    /// The occurrence returned is the lexicographically smallest.
+    ///
+    /// This uses a backtracking search, which is only efficient for short
+    /// patterns.
+    pub fn find_pattern<A: Storage<Inner = usize>>(
+        &self,
+        pattern: &Permutation<A>,
+    ) -> Option<Vec<usize>> {
+        /// Extends a partial occurrence of the pattern.
+        fn extend<S: Storage<Inner = usize>, A: Storage<Inner = usize>>(
+            p: &Permutation<S>,
+            pattern: &Permutation<A>,
+            indices: &mut Vec<usize>,
+        ) -> bool {
+            let t = indices.len();
+            if t == pattern.len() {
+                return true;
+            }
+
+            let start = indices.last().map_or(0, |&i| i + 1);
+
+            // We need to leave room for the rest of the pattern.
+            for i in start..=(p.len() + t).saturating_sub(pattern.len()) {
+                let fits = indices
+                    .iter()
+                    .enumerate()
+                    .all(|(s, &j)| (p[j] < p[i]) == (pattern[s] < pattern[t]));
+
+                if fits {
+                    indices.push(i);
+                    if extend(p, pattern, indices) {
+                        return true;
+                    }
+                    indices.pop();
+                }
+            }
+
+            false
+        }
+
+        if pattern.len() > self.len() {
+            return None;
+        }
+
+        let mut indices = Vec::with_capacity(pattern.len());
+        extend(self, pattern, &mut indices).then_some(indices)
+    }
+
+    /// Returns whether the permutation contains a given pattern.
+    pub fn contains_pattern<A: Storage<Inner = usize>>(&self, pattern: &Permutation<A>) -> bool {
+        self.find_pattern(pattern).is_some()
+    }
+
+    /// Returns whether the permutation avoids a given pattern, meaning it
+    /// doesn't contain it.
+    pub fn avoids_pattern<A: Storage<Inner = usize>>(&self, pattern: &Permutation<A>) -> bool {
+        !self.contains_pattern(pattern)
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Permutation<S> {
+    /// Returns a uniformly random derangement of a given size.
+    ///
+    /// This uses rejection sampling. Since the proportion of derangements
+    /// tends to `1/e`, this takes `O(n)` expected time.
+    ///
+    /// # Panics
+    /// This method will panic if the size is 1, as there are no derangements
+    /// of a single element.
+    pub fn random_derangement(size: Size<S>, rng: &mut Rng) -> Self {
+        assert_ne!(size.value(), 1, "no derangements of size 1");
+
+        loop {
+            let p = Self::random(size, rng);
+            if p.is_derangement() {
+                return p;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        permutation::{PermGroupS, PermutationD, PermutationS},
+        random::Rng,
+        storage::Size,
+    };
+
+    #[test]
+    fn statistics() {
+        let p = PermutationS::new([3, 1, 0, 4, 2].into()).unwrap();
+        assert_eq!(p.fixed_points(), vec![1]);
+        assert!(!p.is_derangement());
+        assert!(!p.is_involution());
+        assert_eq!(p.descents(), vec![0, 1, 3]);
+        assert_eq!(p.inversions(), 5);
+        assert_eq!(p.longest_increasing_subsequence(), vec![0, 2]);
+
+        let s6 = PermGroupS::<6>::symmetric(Default::default());
+        assert_eq!(s6.elements().filter(|p| p.is_derangement()).count(), 265);
+        assert_eq!(s6.elements().filter(|p| p.is_involution()).count(), 76);
+
+        // The inversion count determines the parity.
+        let mut rng = Rng::new(13);
+        for _ in 0..20 {
+            let p = PermutationD::random(Size::from_usize(50), &mut rng);
+            let naive = (0..50)
+                .flat_map(|j| (0..j).map(move |i| (i, j)))
+                .filter(|&(i, j)| p[i] > p[j])
+                .count();
+            assert_eq!(p.inversions(), naive);
+            assert_eq!(p.inversions().is_multiple_of(2), p.sign::<i32>() == 1);
+        }
+    }
+
+    #[test]
+    fn patterns() {
+        let p = PermutationS::new([2, 4, 0, 3, 1].into()).unwrap();
+        let pat = PermutationS::new([1, 2, 0].into()).unwrap();
+        assert_eq!(p.find_pattern(&pat), Some(vec![0, 1, 2]));
+        assert!(p.avoids_pattern(&PermutationS::new([0, 1, 2].into()).unwrap()));
+        assert!(p.contains_pattern(&PermutationS::<0>::identity(Default::default())));
+
+        // The permutations avoiding any pattern of length 3 are counted by the
+        // Catalan numbers.
+        let s6 = PermGroupS::<6>::symmetric(Default::default());
+        for pat in PermGroupS::<3>::symmetric(Default::default()).elements() {
+            assert_eq!(
+                s6.elements().filter(|p| p.avoids_pattern(&pat)).count(),
+                132
+            );
+        }
+    }
+
+    #[test]
+    fn random_derangement() {
+        let mut rng = Rng::new(17);
+        let mut counts = [0; 2];
+        for _ in 0..1000 {
+            let p = PermutationS::<3>::random_derangement(Default::default(), &mut rng);
+            assert!(p.is_derangement());
+            counts[p[0] - 1] += 1;
+        }
+
+        assert!(counts.iter().all(|&c| c > 430 && c < 570));
+    }
+}
@@ -0,0 +1,263 @@
+//! Implements [conjugacy](https://en.wikipedia.org/wiki/Conjugacy_class) of
+//! permutations, and the conjugacy classes of the symmetric groups.
+
+use super::Permutation;
+use crate::{
+    bignat::BigNat,
+    storage::{OwnedStorage, Size, Storage},
+};
+
+impl<S: Storage<Inner = usize>> Permutation<S> {
+    /// Returns whether two permutations are conjugate in the symmetric group.
+    /// This happens precisely when they have the same cycle type.
+    pub fn is_conjugate<A: Storage<Inner = usize>>(&self, q: &Permutation<A>) -> bool {
+        self.len() == q.len() && self.cycle_type() == q.cycle_type()
+    }
+
+    /// Returns the order of the [centralizer](https://en.wikipedia.org/wiki/Centralizer_and_normalizer)
+    /// of the permutation in the symmetric group. That is, the amount of
+    /// permutations that commute with it.
+    pub fn centralizer_order(&self) -> BigNat {
+        centralizer_order(&self.cycle_type())
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Permutation<S> {
+    /// Returns the conjugate `r * self * r⁻¹`.
+    ///
+    /// # Panics
+    /// This method will panic if the permutations have different lengths.
+    pub fn conjugate_by<A: Storage<Inner = usize>>(&self, r: &Permutation<A>) -> Self {
+        assert_eq!(self.len(), r.len(), "size mismatch");
+        let mut entries = vec![0; self.len()];
+        for (i, v) in self.iter().enumerate() {
+            entries[r[i]] = r[v];
+        }
+
+        // Safety: conjugating a permutation gives a permutation.
+        unsafe { Self::new_unchecked(entries.into_iter().collect()) }
+    }
+
+    /// Returns a permutation `r` such that `r * self * r⁻¹ = q`, or `None` if
+    /// both permutations aren't conjugate.
+    pub fn conjugator<A: Storage<Inner = usize>>(&self, q: &Permutation<A>) -> Option<Self> {
+        if self.len() != q.len() {
+            return None;
+        }
+
+        let mut p_cycles = self.cycles();
+        let mut q_cycles = q.cycles();
+        p_cycles.sort_by_key(Vec::len);
+        q_cycles.sort_by_key(Vec::len);
+
+        // Conjugating by `r` maps a cycle `(a₀ a₁ …)` to `(r(a₀) r(a₁) …)`.
+        let mut entries = vec![0; self.len()];
+        for (a, b) in p_cycles.iter().zip(q_cycles.iter()) {
+            if a.len() != b.len() {
+                return None;
+            }
+
+            for (&i, &j) in a.iter().zip(b.iter()) {
+                entries[i] = j;
+            }
+        }
+
+        // Safety: we've mapped the cycles of one permutation bijectively onto
+        // those of the other.
+        unsafe { Some(Self::new_unchecked(entries.into_iter().collect())) }
+    }
+}
+
+/// Returns the order of the centralizer of any permutation with a given cycle
+/// type. If the type has `cₖ` cycles of length `k`, this equals the product of
+/// `k^cₖ cₖ!`.
+fn centralizer_order(cycle_type: &[usize]) -> BigNat {
+    let mut order = BigNat::one();
+    let mut prev = 0;
+    let mut count = 0;
+
+    for &len in cycle_type {
+        count = if len == prev { count + 1 } else { 1 };
+        prev = len;
+        order.mul_small((len * count) as u64);
+    }
+
+    order
+}
+
+/// Returns the [integer partitions](https://en.wikipedia.org/wiki/Integer_partition)
+/// of `n`, each as a non-increasing list of positive parts. The partitions are
+/// sorted in reverse lexicographic order, so that `[n]` comes first.
+pub fn partitions(n: usize) -> Vec<Vec<usize>> {
+    /// Pushes every partition of `n` with parts at most `max`, appended to
+    /// `prefix`.
+    fn push_partitions(n: usize, max: usize, prefix: &mut Vec<usize>, res: &mut Vec<Vec<usize>>) {
+        if n == 0 {
+            res.push(prefix.clone());
+            return;
+        }
+
+        for part in (1..=max.min(n)).rev() {
+            prefix.push(part);
+            push_partitions(n - part, part, prefix, res);
+            prefix.pop();
+        }
+    }
+
+    let mut res = Vec::new();
+    push_partitions(n, n, &mut Vec::new(), &mut res);
+    res
+}
+
+/// A conjugacy class of a symmetric group. These are in bijection with the
+/// integer partitions of its degree, via the cycle type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConjugacyClass {
+    /// The cycle type of the permutations in the class.
+    cycle_type: Vec<usize>,
+
+    /// The amount of permutations in the class.
+    size: BigNat,
+}
+
+impl ConjugacyClass {
+    /// Returns the conjugacy class of permutations with a given cycle type,
+    /// given as a non-increasing list of positive parts.
+    ///
+    /// # Panics
+    /// This method will panic if the cycle type isn't non-increasing or has a
+    /// zero entry.
+    pub fn new(cycle_type: Vec<usize>) -> Self {
+        assert!(
+            cycle_type.windows(2).all(|w| w[0] >= w[1]) && !cycle_type.contains(&0),
+            "invalid cycle type"
+        );
+
+        let mut size = BigNat::one();
+        for i in 2..=cycle_type.iter().sum::<usize>() {
+            size.mul_small(i as u64);
+        }
+
+        let size = size.div_rem(&centralizer_order(&cycle_type)).0;
+        Self { cycle_type, size }
+    }
+
+    /// Returns the cycle type of the permutations in the class.
+    pub fn cycle_type(&self) -> &[usize] {
+        &self.cycle_type
+    }
+
+    /// Returns the degree of the symmetric group the class belongs to.
+    pub fn degree(&self) -> usize {
+        self.cycle_type.iter().sum()
+    }
+
+    /// Returns the amount of permutations in the class.
+    pub fn size(&self) -> &BigNat {
+        &self.size
+    }
+
+    /// Returns the order of the centralizer of any permutation in the class.
+    pub fn centralizer_order(&self) -> BigNat {
+        centralizer_order(&self.cycle_type)
+    }
+
+    /// Returns a permutation in the class, whose cycles consist of consecutive
+    /// entries.
+    ///
+    /// # Panics
+    /// This method will panic if the size is incompatible with the degree of
+    /// the class.
+    pub fn representative<S: OwnedStorage<Inner = usize>>(&self, size: Size<S>) -> Permutation<S> {
+        assert_eq!(size.value(), self.degree(), "size mismatch");
+
+        let mut start = 0;
+        let cycles: Vec<Vec<_>> = self
+            .cycle_type
+            .iter()
+            .map(|&len| {
+                start += len;
+                (start - len..start).collect()
+            })
+            .collect();
+
+        Permutation::from_cycles(size, &cycles).unwrap()
+    }
+
+    /// Returns whether a permutation belongs to the class.
+    pub fn contains<S: Storage<Inner = usize>>(&self, p: &Permutation<S>) -> bool {
+        p.cycle_type() == self.cycle_type
+    }
+}
+
+/// Returns the conjugacy classes of the symmetric group of a given degree,
+/// indexed by the integer partitions of the degree, in the same order as
+/// [`partitions`].
+pub fn conjugacy_classes(n: usize) -> Vec<ConjugacyClass> {
+    partitions(n).into_iter().map(ConjugacyClass::new).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        permutation::{PermGroupS, PermutationD, PermutationS},
+        random::Rng,
+    };
+
+    #[test]
+    fn conjugator() {
+        let mut rng = Rng::new(3);
+        let p = PermutationS::<8>::random(Default::default(), &mut rng);
+        let r = PermutationS::<8>::random(Default::default(), &mut rng);
+        let q = p.conjugate_by(&r);
+        assert_eq!(q, r * p * r.inv());
+
+        assert!(p.is_conjugate(&q));
+        let s = p.conjugator(&q).unwrap();
+        assert_eq!(p.conjugate_by(&s), q);
+
+        let id = PermutationS::<8>::identity(Default::default());
+        assert_eq!(p.conjugator(&id).is_some(), p.is_identity());
+    }
+
+    #[test]
+    #[should_panic]
+    fn conjugate_by_size_mismatch() {
+        let p = PermutationD::identity(Size::from_usize(3));
+        let r = PermutationD::identity(Size::from_usize(4));
+        p.conjugate_by(&r);
+    }
+
+    #[test]
+    fn centralizer() {
+        let p =
+            PermutationS::<6>::from_cycles(Default::default(), &[vec![0, 1], vec![2, 3]]).unwrap();
+        let s6 = PermGroupS::<6>::symmetric(Default::default());
+        let count = s6.elements().filter(|q| q * p == p * q).count();
+        assert_eq!(p.centralizer_order(), BigNat::from(count));
+        assert_eq!(p.centralizer_order(), BigNat::from(16u64));
+    }
+
+    #[test]
+    fn classes() {
+        assert_eq!(partitions(5).len(), 7);
+        assert_eq!(partitions(0), vec![Vec::<usize>::new()]);
+
+        let classes = conjugacy_classes(4);
+        let sizes: Vec<_> = classes
+            .iter()
+            .map(|c| c.size().to_u128().unwrap())
+            .collect();
+        assert_eq!(sizes, vec![6, 8, 3, 6, 1]);
+
+        let mut total = BigNat::zero();
+        for class in conjugacy_classes(7) {
+            let p: PermutationS<7> = class.representative(Default::default());
+            assert!(class.contains(&p));
+            total = &total + class.size();
+        }
+
+        assert_eq!(total, BigNat::from(5040u64));
+    }
+}
@@ -0,0 +1,370 @@
+//! Implements the action of a permutation group on points, tuples, and sets.
+
+use std::{collections::HashSet, hash::Hash};
+
+use super::{Letter, PermGroup, Word};
+use crate::{permutation::Permutation, storage::OwnedStorage};
+
+/// A [Schreier vector](https://en.wikipedia.org/wiki/Schreier_vector) for the
+/// orbit of a point under a [`PermGroup`].
+///
+/// This stores a spanning tree of the orbit, whose edges are labeled by the
+/// generators of the group. This allows us to recover an element mapping the
+/// root to any point in its orbit, without having to store one explicitly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchreierVector {
+    /// The root of the orbit.
+    root: usize,
+
+    /// For every point `q` in the orbit other than the root, the index of a
+    /// generator `s`, and the point `s⁻¹(q)`, which is closer to the root.
+    entries: Vec<Option<(usize, usize)>>,
+}
+
+impl SchreierVector {
+    /// Returns the root of the orbit.
+    pub fn root(&self) -> usize {
+        self.root
+    }
+
+    /// Returns whether a point is in the orbit.
+    pub fn contains(&self, q: usize) -> bool {
+        q == self.root || self.entries.get(q).is_some_and(Option::is_some)
+    }
+
+    /// Returns the index of the generator that labels the edge into `q`, or
+    /// `None` if `q` is the root or not in the orbit.
+    pub fn get(&self, q: usize) -> Option<usize> {
+        self.entries.get(q).copied().flatten().map(|(s, _)| s)
+    }
+
+    /// Returns the points of the orbit, in increasing order.
+    pub fn orbit(&self) -> Vec<usize> {
+        (0..self.entries.len())
+            .filter(|&q| self.contains(q))
+            .collect()
+    }
+
+    /// Returns a word in the generators of the group, which evaluates to an
+    /// element mapping the root to `q`. Returns `None` if `q` isn't in the
+    /// orbit.
+    pub fn word(&self, mut q: usize) -> Option<Word> {
+        if !self.contains(q) {
+            return None;
+        }
+
+        let mut word = Word::new();
+        while let Some((s, prev)) = self.entries[q] {
+            word.push(Letter::new(s));
+            q = prev;
+        }
+
+        Some(word)
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize> + Clone> PermGroup<S> {
+    /// Returns the orbit of a value under some action of the generators, in
+    /// order of discovery.
+    fn orbit_by<T: Clone + Eq + Hash, F: FnMut(&Permutation<S>, &T) -> T>(
+        &self,
+        x: T,
+        mut act: F,
+    ) -> Vec<T> {
+        let mut orbit = vec![x.clone()];
+        let mut seen = HashSet::new();
+        seen.insert(x);
+
+        let mut k = 0;
+        while k < orbit.len() {
+            for s in &self.generators {
+                let y = act(s, &orbit[k]);
+                if seen.insert(y.clone()) {
+                    orbit.push(y);
+                }
+            }
+
+            k += 1;
+        }
+
+        orbit
+    }
+
+    /// Returns the orbit of a point, in order of discovery.
+    ///
+    /// # Panics
+    /// This method will panic if the point is out of bounds.
+    pub fn orbit(&self, p: usize) -> Vec<usize> {
+        assert!(p < self.size.value(), "point out of bounds");
+        self.orbit_by(p, |s, &q| s[q])
+    }
+
+    /// Returns the orbits of the group, which partition its points. Every
+    /// orbit is sorted, and they're sorted by their smallest element.
+    pub fn orbits(&self) -> Vec<Vec<usize>> {
+        let mut checked = vec![false; self.size.value()];
+        let mut orbits = Vec::new();
+
+        for p in 0..self.size.value() {
+            if !checked[p] {
+                let mut orbit = self.orbit(p);
+                orbit.sort_unstable();
+
+                for &q in &orbit {
+                    checked[q] = true;
+                }
+
+                orbits.push(orbit);
+            }
+        }
+
+        orbits
+    }
+
+    /// Returns whether the group is
+    /// [transitive](https://en.wikipedia.org/wiki/Group_action#Transitivity_properties),
+    /// meaning it has a single orbit.
+    pub fn is_transitive(&self) -> bool {
+        self.size.value() == 0 || self.orbit(0).len() == self.size.value()
+    }
+
+    /// Returns the Schreier vector for the orbit of a point.
+    ///
+    /// # Panics
+    /// This method will panic if the point is out of bounds.
+    pub fn schreier_vector(&self, p: usize) -> SchreierVector {
+        assert!(p < self.size.value(), "point out of bounds");
+
+        let mut entries = vec![None; self.size.value()];
+        let mut orbit = vec![p];
+
+        let mut k = 0;
+        while k < orbit.len() {
+            let q = orbit[k];
+            for (i, s) in self.generators.iter().enumerate() {
+                let r = s[q];
+                if r != p && entries[r].is_none() {
+                    entries[r] = Some((i, q));
+                    orbit.push(r);
+                }
+            }
+
+            k += 1;
+        }
+
+        SchreierVector { root: p, entries }
+    }
+
+    /// Returns the orbit of a tuple of points, under the componentwise action
+    /// of the group, in order of discovery.
+    ///
+    /// # Panics
+    /// This method will panic if any point is out of bounds.
+    pub fn tuple_orbit(&self, tuple: &[usize]) -> Vec<Vec<usize>> {
+        assert!(
+            tuple.iter().all(|&p| p < self.size.value()),
+            "point out of bounds"
+        );
+
+        self.orbit_by(tuple.to_vec(), |s, t| t.iter().map(|&q| s[q]).collect())
+    }
+
+    /// Returns the orbit of a set of points, in order of discovery. Sets are
+    /// represented as sorted lists without repetitions.
+    ///
+    /// # Panics
+    /// This method will panic if any point is out of bounds.
+    pub fn set_orbit(&self, set: &[usize]) -> Vec<Vec<usize>> {
+        let set = sorted_set(set);
+        assert!(
+            set.iter().all(|&p| p < self.size.value()),
+            "point out of bounds"
+        );
+
+        self.orbit_by(set, |s, t| {
+            let mut u: Vec<_> = t.iter().map(|&q| s[q]).collect();
+            u.sort_unstable();
+            u
+        })
+    }
+
+    /// Returns the stabilizer of a point.
+    ///
+    /// # Panics
+    /// This method will panic if the point is out of bounds.
+    pub fn stabilizer(&self, p: usize) -> Self {
+        self.pointwise_stabilizer(&[p])
+    }
+
+    /// Returns the pointwise stabilizer of some points. That is, the subgroup
+    /// of elements fixing every one of them.
+    ///
+    /// # Panics
+    /// This method will panic if any point is out of bounds.
+    pub fn pointwise_stabilizer(&self, points: &[usize]) -> Self {
+        let points = sorted_set(points);
+        let chain = Self::with_base(self.size, self.generators.clone(), &points);
+
+        Self::new(
+            self.size,
+            chain
+                .basic_stabilizer_generators(points.len())
+                .into_iter()
+                .cloned()
+                .collect(),
+        )
+    }
+
+    /// Returns the setwise stabilizer of some points. That is, the subgroup of
+    /// elements mapping the set of these points to itself.
+    ///
+    /// # Panics
+    /// This method will panic if any point is out of bounds.
+    pub fn setwise_stabilizer(&self, set: &[usize]) -> Self {
+        let set = sorted_set(set);
+        let k = set.len();
+        let chain = Self::with_base(self.size, self.generators.clone(), &set);
+
+        let mut in_set = vec![false; self.size.value()];
+        for &p in &set {
+            in_set[p] = true;
+        }
+
+        // The pointwise stabilizer is contained in the setwise stabilizer.
+        let mut stab = Self::new(
+            self.size,
+            chain
+                .basic_stabilizer_generators(k)
+                .into_iter()
+                .cloned()
+                .collect(),
+        );
+        if k == 0 {
+            return stab;
+        }
+
+        // Any element of the group can be written as `u₀ u₁ … uₖ₋₁ h`, where
+        // `h` fixes the set pointwise, and each `uᵢ` is in the transversal of
+        // the `i`-th level. We search for the products mapping the first `k`
+        // base points back into the set, and add those we haven't found yet
+        // to the stabilizer chain.
+        //
+        // If `g` is in the setwise stabilizer, then so is `sg` for any `s` in
+        // the subgroup found so far. So once we've searched the elements
+        // mapping `b₀` to some point, we can skip every other point in its
+        // orbit under this subgroup. In particular, we search the elements
+        // fixing `b₀` first, after which we can skip any product that's
+        // already in the subgroup.
+        let mut searched: Vec<usize> = Vec::new();
+        for &q in &chain.levels[0].orbit {
+            if !in_set[q] {
+                continue;
+            }
+
+            let orbit = stab.orbit(q);
+            if searched.iter().any(|r| orbit.contains(r)) {
+                continue;
+            }
+
+            let first = searched.is_empty();
+            searched.push(q);
+
+            let u = &chain.levels[0].transversal[q].as_ref().unwrap().perm;
+            let mut stack = vec![(1, u.clone())];
+            while let Some((i, p)) = stack.pop() {
+                if i == k {
+                    if !stab.contains(&p) {
+                        stab.push_generator(p);
+                    }
+
+                    continue;
+                }
+
+                if !first && stab.contains(&p) {
+                    continue;
+                }
+
+                let level = &chain.levels[i];
+                for &q in &level.orbit {
+                    if in_set[p[q]] {
+                        let u = &level.transversal[q].as_ref().unwrap().perm;
+                        stack.push((i + 1, Permutation::compose(&p, u)));
+                    }
+                }
+            }
+        }
+
+        stab
+    }
+}
+
+/// Sorts a list of points and removes any repetitions.
+fn sorted_set(points: &[usize]) -> Vec<usize> {
+    let mut set = points.to_vec();
+    set.sort_unstable();
+    set.dedup();
+    set
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        bignat::BigNat,
+        permutation::{PermGroupD, PermGroupS},
+        storage::Size,
+    };
+
+    #[test]
+    fn orbits() {
+        let size = Size::from_usize(6);
+        let g = PermGroupD::new(
+            size,
+            vec![Permutation::from_cycles(size, &[vec![0, 1], vec![2, 3, 4]]).unwrap()],
+        );
+
+        assert_eq!(g.orbits(), vec![vec![0, 1], vec![2, 3, 4], vec![5]]);
+        assert!(!g.is_transitive());
+        assert!(PermGroupD::cyclic(size).is_transitive());
+
+        let sv = g.schreier_vector(2);
+        assert_eq!(sv.orbit(), vec![2, 3, 4]);
+        assert_eq!(sv.get(2), None);
+        assert!(!sv.contains(0));
+        assert_eq!(g.evaluate(&sv.word(4).unwrap())[2], 4);
+    }
+
+    #[test]
+    fn tuples_and_sets() {
+        let d6 = PermGroupS::<6>::dihedral(Default::default());
+        assert_eq!(d6.tuple_orbit(&[0, 1]).len(), 12);
+        assert_eq!(d6.set_orbit(&[1, 0]).len(), 6);
+        assert_eq!(d6.set_orbit(&[0, 3]).len(), 3);
+    }
+
+    #[test]
+    fn stabilizers() {
+        let s5 = PermGroupS::<5>::symmetric(Default::default());
+        assert_eq!(s5.stabilizer(2).order(), BigNat::from(24u64));
+        assert_eq!(s5.pointwise_stabilizer(&[0, 1]).order(), BigNat::from(6u64));
+        assert_eq!(s5.setwise_stabilizer(&[0, 1]).order(), BigNat::from(12u64));
+
+        let d6 = PermGroupS::<6>::dihedral(Default::default());
+        let stab = d6.setwise_stabilizer(&[0, 3]);
+        assert_eq!(stab.order(), BigNat::from(4u64));
+        assert!(stab
+            .elements()
+            .all(|p| d6.contains(&p) && [p[0], p[3]].contains(&3)));
+
+        // We compare against a brute force search.
+        let a6 = PermGroupS::<6>::alternating(Default::default());
+        for set in [vec![1, 3, 4], vec![0, 5], vec![2], vec![]] {
+            let stab = a6.setwise_stabilizer(&set);
+            let count = a6
+                .elements()
+                .filter(|p| set.iter().all(|&q| set.contains(&p[q])))
+                .count();
+            assert_eq!(stab.order(), BigNat::from(count as u64));
+        }
+    }
+}
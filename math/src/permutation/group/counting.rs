@@ -0,0 +1,178 @@
+//! Implements [Burnside's lemma](https://en.wikipedia.org/wiki/Burnside%27s_lemma)
+//! and [Pólya enumeration](https://en.wikipedia.org/wiki/P%C3%B3lya_enumeration_theorem)
+//! for permutation groups.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Display},
+};
+
+use super::PermGroup;
+use crate::{bignat::BigNat, permutation::Permutation, storage::OwnedStorage};
+
+/// The [cycle index](https://en.wikipedia.org/wiki/Cycle_index) of a
+/// permutation group. This is the polynomial
+///
+/// ```txt
+/// Z(G) = 1/|G| Σ x₁^c₁(g) x₂^c₂(g) ⋯ xₙ^cₙ(g),
+/// ```
+///
+/// where the sum ranges over all elements of the group, and `cₖ(g)` stands for
+/// the number of cycles of length `k` of `g`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleIndex {
+    /// The order of the group.
+    order: BigNat,
+
+    /// Maps the exponents of every monomial to the amount of elements giving
+    /// rise to it. The `k`-th exponent is that of `xₖ₊₁`, and there are no
+    /// trailing zeros.
+    terms: BTreeMap<Vec<usize>, u64>,
+}
+
+impl CycleIndex {
+    /// Returns the order of the group.
+    pub fn order(&self) -> &BigNat {
+        &self.order
+    }
+
+    /// Returns an iterator over the monomials of the cycle index, together
+    /// with the amount of elements giving rise to each.
+    pub fn terms(&self) -> impl Iterator<Item = (&[usize], u64)> {
+        self.terms
+            .iter()
+            .map(|(exps, &count)| (exps.as_slice(), count))
+    }
+
+    /// Substitutes every variable `xₖ` by `f(k)`, and evaluates the result.
+    ///
+    /// This will only be accurate when the sum over the elements of the group
+    /// is divisible by its order, which is the case for any substitution that
+    /// counts objects.
+    pub fn substitute<F: FnMut(usize) -> BigNat>(&self, mut f: F) -> BigNat {
+        let mut sum = BigNat::zero();
+
+        for (exps, &count) in &self.terms {
+            let mut term = BigNat::from(count);
+            for (k, &e) in exps.iter().enumerate() {
+                if e != 0 {
+                    term = &term * &f(k + 1).pow(e as u32);
+                }
+            }
+
+            sum = &sum + &term;
+        }
+
+        let (quot, rem) = sum.div_rem(&self.order);
+        debug_assert!(rem.is_zero(), "sum not divisible by group order");
+        quot
+    }
+
+    /// Returns the number of colorings of the points with a given amount of
+    /// colors, up to the action of the group.
+    pub fn count_colorings(&self, colors: u64) -> BigNat {
+        let colors = BigNat::from(colors);
+        self.substitute(|_| colors.clone())
+    }
+}
+
+impl Display for CycleIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+
+        for (i, (exps, &count)) in self.terms.iter().rev().enumerate() {
+            if i != 0 {
+                write!(f, " + ")?;
+            }
+
+            let is_one = exps.iter().all(|&e| e == 0);
+            if count != 1 || is_one {
+                write!(f, "{}", count)?;
+            }
+
+            for (k, &e) in exps.iter().enumerate() {
+                match e {
+                    0 => {}
+                    1 => write!(f, "x{}", k + 1)?,
+                    _ => write!(f, "x{}^{}", k + 1, e)?,
+                }
+            }
+        }
+
+        write!(f, ")/{}", self.order)
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize> + Clone> PermGroup<S> {
+    /// Returns the average of a function over the elements of the group.
+    ///
+    /// By Burnside's lemma, if the function counts the objects fixed by each
+    /// element, this gives the number of orbits of these objects.
+    pub fn burnside<F: FnMut(&Permutation<S>) -> BigNat>(&self, mut f: F) -> BigNat {
+        let mut sum = BigNat::zero();
+        for g in self.elements() {
+            sum = &sum + &f(&g);
+        }
+
+        let (quot, rem) = sum.div_rem(&self.order());
+        debug_assert!(rem.is_zero(), "sum not divisible by group order");
+        quot
+    }
+
+    /// Returns the cycle index of the group. This iterates over all of its
+    /// elements.
+    pub fn cycle_index(&self) -> CycleIndex {
+        let mut terms = BTreeMap::new();
+
+        for g in self.elements() {
+            let mut exps = Vec::new();
+            for len in g.cycle_type() {
+                if exps.len() < len {
+                    exps.resize(len, 0);
+                }
+
+                exps[len - 1] += 1;
+            }
+
+            *terms.entry(exps).or_insert(0) += 1;
+        }
+
+        CycleIndex {
+            order: self.order(),
+            terms,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{bignat::BigNat, permutation::PermGroupS};
+
+    #[test]
+    fn cycle_index() {
+        let s3 = PermGroupS::<3>::symmetric(Default::default()).cycle_index();
+        assert_eq!(s3.to_string(), "(x1^3 + 3x1x2 + 2x3)/6");
+        assert_eq!(s3.count_colorings(2), BigNat::from(4u64));
+    }
+
+    #[test]
+    fn necklaces() {
+        // Binary necklaces and bracelets of length 6.
+        let c6 = PermGroupS::<6>::cyclic(Default::default());
+        let d6 = PermGroupS::<6>::dihedral(Default::default());
+        assert_eq!(c6.cycle_index().count_colorings(2), BigNat::from(14u64));
+        assert_eq!(d6.cycle_index().count_colorings(2), BigNat::from(13u64));
+
+        // Ternary bracelets of length 4.
+        let d4 = PermGroupS::<4>::dihedral(Default::default());
+        assert_eq!(d4.cycle_index().count_colorings(3), BigNat::from(21u64));
+    }
+
+    #[test]
+    fn burnside() {
+        let d6 = PermGroupS::<6>::dihedral(Default::default());
+        let orbits =
+            d6.burnside(|g| BigNat::from(g.iter().enumerate().filter(|(i, v)| i == v).count()));
+        assert_eq!(orbits, BigNat::one());
+    }
+}
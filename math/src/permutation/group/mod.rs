@@ -0,0 +1,710 @@
+//! Declares permutation groups, represented through a base and strong
+//! generating set.
+
+mod action;
+mod counting;
+
+pub use action::*;
+pub use counting::*;
+
+use super::Permutation;
+use crate::{
+    bignat::BigNat,
+    random::Rng,
+    storage::{ArrayStorageE, OwnedStorage, Size, VecStorageE},
+};
+
+/// A letter in a word over the generators of a [`PermGroup`]. This is either
+/// one of the generators or its inverse.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Letter {
+    /// The index of the generator.
+    pub generator: usize,
+
+    /// Whether the letter stands for the inverse of the generator.
+    pub inverse: bool,
+}
+
+impl Letter {
+    /// Initializes a letter representing a generator.
+    pub fn new(generator: usize) -> Self {
+        Self {
+            generator,
+            inverse: false,
+        }
+    }
+
+    /// Returns the letter representing the inverse of `self`.
+    pub fn inv(self) -> Self {
+        Self {
+            generator: self.generator,
+            inverse: !self.inverse,
+        }
+    }
+}
+
+/// A word over the generators of a [`PermGroup`]. The word `[a, b, c]` stands
+/// for the composition `a * b * c`, so that `c` is applied first.
+pub type Word = Vec<Letter>;
+
+/// Appends a letter to a word, cancelling it with the last letter if they're
+/// inverses.
+fn push_reduced(word: &mut Word, l: Letter) {
+    if word.last() == Some(&l.inv()) {
+        word.pop();
+    } else {
+        word.push(l);
+    }
+}
+
+/// A reference to a node in a straight-line program, or to its inverse.
+#[derive(Clone, Copy, Debug)]
+struct Slp {
+    /// The index of the node.
+    node: usize,
+
+    /// Whether we refer to the inverse of the node.
+    inverse: bool,
+}
+
+impl Slp {
+    /// A reference to the identity node, which is always the first one.
+    const IDENTITY: Self = Self {
+        node: 0,
+        inverse: false,
+    };
+
+    /// Returns a reference to the inverse of the node.
+    fn inv(self) -> Self {
+        Self {
+            node: self.node,
+            inverse: !self.inverse,
+        }
+    }
+}
+
+/// A node in a [straight-line program](https://en.wikipedia.org/wiki/Straight-line_program).
+///
+/// Storing the words for the elements of a stabilizer chain explicitly takes
+/// up space exponential in the length of the base. Instead, we record how every
+/// element was built, and only expand this into a word when needed.
+#[derive(Clone, Copy, Debug)]
+enum Node {
+    /// The identity.
+    Identity,
+
+    /// One of the generators of the group.
+    Generator(usize),
+
+    /// The product of two previous nodes.
+    Product(Slp, Slp),
+}
+
+/// A permutation, together with a straight-line program that evaluates to it.
+#[derive(Clone, Debug)]
+struct Element<S: OwnedStorage<Inner = usize>> {
+    /// The permutation.
+    perm: Permutation<S>,
+
+    /// The straight-line program evaluating to the permutation.
+    word: Slp,
+}
+
+impl<S: OwnedStorage<Inner = usize>> Element<S> {
+    /// Returns the identity element.
+    fn identity(size: Size<S>) -> Self {
+        Self {
+            perm: Permutation::identity(size),
+            word: Slp::IDENTITY,
+        }
+    }
+
+    /// Composes two elements, adding a new node to the straight-line program if
+    /// needed.
+    fn compose(&self, rhs: &Self, nodes: &mut Vec<Node>) -> Self {
+        let word = if self.word.node == 0 {
+            rhs.word
+        } else if rhs.word.node == 0 {
+            self.word
+        } else {
+            nodes.push(Node::Product(self.word, rhs.word));
+            Slp {
+                node: nodes.len() - 1,
+                inverse: false,
+            }
+        };
+
+        Self {
+            perm: Permutation::compose(&self.perm, &rhs.perm),
+            word,
+        }
+    }
+
+    /// Returns the inverse of an element.
+    fn inv(&self) -> Self {
+        Self {
+            perm: self.perm.inv(),
+            word: self.word.inv(),
+        }
+    }
+}
+
+/// A level in the stabilizer chain of a [`PermGroup`].
+#[derive(Clone, Debug)]
+struct Level<S: OwnedStorage<Inner = usize>> {
+    /// The base point of the level.
+    point: usize,
+
+    /// The strong generators of the level. These all fix every previous base
+    /// point, and generate the pointwise stabilizer of those points.
+    gens: Vec<Element<S>>,
+
+    /// The orbit of the base point, in order of discovery.
+    orbit: Vec<usize>,
+
+    /// For each point in the orbit, the amount of generators whose Schreier
+    /// generators have already been checked.
+    checked: Vec<usize>,
+
+    /// For every point `q` in the orbit, an element mapping the base point to
+    /// `q`.
+    transversal: Vec<Option<Element<S>>>,
+}
+
+impl<S: OwnedStorage<Inner = usize> + Clone> Level<S> {
+    /// Initializes a level with a given base point and no generators.
+    fn new(size: Size<S>, point: usize) -> Self {
+        let mut transversal = vec![None; size.value()];
+        transversal[point] = Some(Element::identity(size));
+
+        Self {
+            point,
+            gens: Vec::new(),
+            orbit: vec![point],
+            checked: vec![0],
+            transversal,
+        }
+    }
+}
+
+/// A group of permutations, given by a set of generators.
+///
+/// On construction, we run the
+/// [Schreier–Sims algorithm](https://en.wikipedia.org/wiki/Schreier%E2%80%93Sims_algorithm)
+/// to find a base and strong generating set. That is, a sequence of points
+/// `b₀, b₁, …` such that only the identity fixes all of them, and generators
+/// for each of the pointwise stabilizers `G⁽ⁱ⁾` of `b₀, …, bᵢ₋₁`. This allows
+/// us to efficiently compute the order of the group, test membership, and
+/// sample random elements.
+#[derive(Clone, Debug)]
+pub struct PermGroup<S: OwnedStorage<Inner = usize>> {
+    /// The size of the permutations in the group.
+    size: Size<S>,
+
+    /// The generators of the group.
+    generators: Vec<Permutation<S>>,
+
+    /// The stabilizer chain.
+    levels: Vec<Level<S>>,
+
+    /// The straight-line program for the elements in the stabilizer chain.
+    nodes: Vec<Node>,
+}
+
+/// A group of statically-sized permutations.
+pub type PermGroupS<const N: usize> = PermGroup<ArrayStorageE<usize, N>>;
+
+/// A group of dynamically-sized permutations.
+pub type PermGroupD = PermGroup<VecStorageE<usize>>;
+
+impl<S: OwnedStorage<Inner = usize> + Clone> PermGroup<S> {
+    /// Returns the group generated by a given set of permutations.
+    ///
+    /// # Panics
+    /// This method will panic if any generator doesn't have the given size.
+    pub fn new(size: Size<S>, generators: Vec<Permutation<S>>) -> Self {
+        Self::with_base(size, generators, &[])
+    }
+
+    /// Returns the group generated by a given set of permutations, such that
+    /// its base starts with the given points.
+    ///
+    /// # Panics
+    /// This method will panic if any generator doesn't have the given size, or
+    /// if any point is out of bounds or repeated.
+    pub fn with_base(size: Size<S>, generators: Vec<Permutation<S>>, base: &[usize]) -> Self {
+        for (i, &p) in base.iter().enumerate() {
+            assert!(p < size.value(), "base point out of bounds");
+            assert!(!base[..i].contains(&p), "repeated base point");
+        }
+
+        let mut group = Self {
+            size,
+            generators: Vec::new(),
+            levels: base.iter().map(|&p| Level::new(size, p)).collect(),
+            nodes: vec![Node::Identity],
+        };
+
+        for g in generators {
+            group.push_generator(g);
+        }
+
+        group
+    }
+
+    /// Adds a generator to the group, extending the stabilizer chain in place.
+    ///
+    /// # Panics
+    /// This method will panic if the generator doesn't have the right size.
+    pub(super) fn push_generator(&mut self, g: Permutation<S>) {
+        assert_eq!(g.len(), self.size.value(), "size mismatch");
+
+        self.nodes.push(Node::Generator(self.generators.len()));
+        let word = Slp {
+            node: self.nodes.len() - 1,
+            inverse: false,
+        };
+
+        self.insert(
+            0,
+            Element {
+                perm: g.clone(),
+                word,
+            },
+        );
+        self.generators.push(g);
+    }
+
+    /// Returns the trivial group.
+    pub fn trivial(size: Size<S>) -> Self {
+        Self::new(size, Vec::new())
+    }
+
+    /// Returns the [symmetric group](https://en.wikipedia.org/wiki/Symmetric_group)
+    /// of all permutations of a given size. This is generated by the
+    /// transposition `(0 1)` and the cycle `(0 1 … n-1)`.
+    pub fn symmetric(size: Size<S>) -> Self {
+        let n = size.value();
+        if n < 2 {
+            return Self::trivial(size);
+        }
+
+        let cycle: Vec<_> = (0..n).collect();
+        Self::new(
+            size,
+            vec![
+                Permutation::from_cycles(size, &[[0, 1]]).unwrap(),
+                Permutation::from_cycles(size, &[cycle]).unwrap(),
+            ],
+        )
+    }
+
+    /// Returns the [alternating group](https://en.wikipedia.org/wiki/Alternating_group)
+    /// of all even permutations of a given size. This is generated by the
+    /// 3-cycles `(0 1 i)`.
+    pub fn alternating(size: Size<S>) -> Self {
+        let gens = (2..size.value())
+            .map(|i| Permutation::from_cycles(size, &[[0, 1, i]]).unwrap())
+            .collect();
+        Self::new(size, gens)
+    }
+
+    /// Returns the cyclic group generated by the rotation `i ↦ i + 1`.
+    pub fn cyclic(size: Size<S>) -> Self {
+        let cycle: Vec<_> = (0..size.value()).collect();
+        Self::new(
+            size,
+            vec![Permutation::from_cycles(size, &[cycle]).unwrap()],
+        )
+    }
+
+    /// Returns the [dihedral group](https://en.wikipedia.org/wiki/Dihedral_group)
+    /// of symmetries of a polygon whose vertices are labeled in order. This is
+    /// generated by the rotation `i ↦ i + 1` and the reflection `i ↦ -i`.
+    pub fn dihedral(size: Size<S>) -> Self {
+        let n = size.value();
+        let rotation = (0..n).map(|i| (i + 1) % n).collect();
+        let reflection = (0..n).map(|i| (n - i) % n).collect();
+        Self::new(size, vec![rotation, reflection])
+    }
+
+    /// Sifts an element through the stabilizer chain, starting from a given
+    /// level. Returns the residue, and the level at which it stopped.
+    fn sift(&mut self, from: usize, mut g: Element<S>) -> (Element<S>, usize) {
+        for (l, level) in self.levels.iter().enumerate().skip(from) {
+            match &level.transversal[g.perm[level.point]] {
+                Some(u) => g = u.inv().compose(&g, &mut self.nodes),
+                None => return (g, l),
+            }
+        }
+
+        (g, self.levels.len())
+    }
+
+    /// Adds an element fixing the first `from` base points to the group,
+    /// updating the stabilizer chain accordingly.
+    fn insert(&mut self, from: usize, g: Element<S>) {
+        let (r, to) = self.sift(from, g);
+        if r.perm.is_identity() {
+            return;
+        }
+
+        if to == self.levels.len() {
+            // Safety: a non-identity permutation moves some point.
+            let point = r.perm.iter().enumerate().position(|(i, v)| i != v).unwrap();
+            self.levels.push(Level::new(self.size, point));
+        }
+
+        // We update the deepest levels first, so that every Schreier generator
+        // we find is sifted through an up to date chain.
+        for l in (from..=to).rev() {
+            self.levels[l].gens.push(r.clone());
+            self.extend(l);
+        }
+    }
+
+    /// Extends the orbit and transversal of a level after adding generators
+    /// to it, and adds any Schreier generators that don't sift through the
+    /// lower levels.
+    fn extend(&mut self, l: usize) {
+        let mut k = 0;
+
+        while k < self.levels[l].orbit.len() {
+            let level = &mut self.levels[l];
+            if level.checked[k] == level.gens.len() {
+                k += 1;
+                continue;
+            }
+
+            let s = &level.gens[level.checked[k]];
+            level.checked[k] += 1;
+
+            let p = level.orbit[k];
+            let q = s.perm[p];
+            let sp = s.compose(level.transversal[p].as_ref().unwrap(), &mut self.nodes);
+
+            match &level.transversal[q] {
+                // A new point in the orbit.
+                None => {
+                    level.transversal[q] = Some(sp);
+                    level.orbit.push(q);
+                    level.checked.push(0);
+                }
+
+                // A Schreier generator, which fixes the base point.
+                Some(u) => {
+                    let schreier = u.inv().compose(&sp, &mut self.nodes);
+                    self.insert(l + 1, schreier);
+                }
+            }
+        }
+    }
+
+    /// Returns the size of the permutations in the group.
+    pub fn size(&self) -> Size<S> {
+        self.size
+    }
+
+    /// Returns the generators of the group.
+    pub fn generators(&self) -> &[Permutation<S>] {
+        &self.generators
+    }
+
+    /// Returns the base of the group.
+    pub fn base(&self) -> Vec<usize> {
+        self.levels.iter().map(|level| level.point).collect()
+    }
+
+    /// Returns a strong generating set for the group, relative to its base.
+    pub fn strong_generators(&self) -> Vec<&Permutation<S>> {
+        let mut gens: Vec<&Permutation<S>> = Vec::new();
+        for g in self.levels.iter().flat_map(|level| &level.gens) {
+            if !gens.iter().any(|h| h.iter().eq(g.perm.iter())) {
+                gens.push(&g.perm);
+            }
+        }
+
+        gens
+    }
+
+    /// Returns the orbit of the `i`-th base point under the pointwise
+    /// stabilizer of the previous base points.
+    ///
+    /// # Panics
+    /// This method will panic if `i` is not less than the length of the base.
+    pub fn basic_orbit(&self, i: usize) -> &[usize] {
+        &self.levels[i].orbit
+    }
+
+    /// Returns a generating set for the pointwise stabilizer of the first `i`
+    /// base points.
+    ///
+    /// # Panics
+    /// This method will panic if `i` is greater than the length of the base.
+    pub fn basic_stabilizer_generators(&self, i: usize) -> Vec<&Permutation<S>> {
+        assert!(i <= self.levels.len(), "index out of bounds");
+
+        self.levels.get(i).map_or(Vec::new(), |level| {
+            level.gens.iter().map(|g| &g.perm).collect()
+        })
+    }
+
+    /// Returns the order of the group. This is the product of the lengths of
+    /// its basic orbits.
+    pub fn order(&self) -> BigNat {
+        let mut order = BigNat::one();
+        for level in &self.levels {
+            order.mul_small(level.orbit.len() as u64);
+        }
+
+        order
+    }
+
+    /// Returns whether the group is trivial.
+    pub fn is_trivial(&self) -> bool {
+        self.levels.iter().all(|level| level.orbit.len() == 1)
+    }
+
+    /// Returns whether the group contains a given permutation.
+    pub fn contains(&self, p: &Permutation<S>) -> bool {
+        if p.len() != self.size.value() {
+            return false;
+        }
+
+        let mut g = p.clone();
+        for level in &self.levels {
+            match &level.transversal[g[level.point]] {
+                Some(u) => g = Permutation::compose(&u.perm.inv(), &g),
+                None => return false,
+            }
+        }
+
+        g.is_identity()
+    }
+
+    /// Writes an element of the group as a word in its generators, or returns
+    /// `None` if the permutation isn't in the group.
+    ///
+    /// There's no guarantee that the word is of minimal length.
+    pub fn factorize(&self, p: &Permutation<S>) -> Option<Word> {
+        if p.len() != self.size.value() {
+            return None;
+        }
+
+        let mut g = p.clone();
+        let mut word = Word::new();
+        for level in &self.levels {
+            let u = level.transversal[g[level.point]].as_ref()?;
+            g = Permutation::compose(&u.perm.inv(), &g);
+            self.expand(u.word, &mut word);
+        }
+
+        g.is_identity().then_some(word)
+    }
+
+    /// Expands a straight-line program into a word, and appends it to another.
+    fn expand(&self, slp: Slp, word: &mut Word) {
+        let mut stack = vec![slp];
+
+        while let Some(slp) = stack.pop() {
+            match self.nodes[slp.node] {
+                Node::Identity => {}
+                Node::Generator(generator) => push_reduced(
+                    word,
+                    Letter {
+                        generator,
+                        inverse: slp.inverse,
+                    },
+                ),
+
+                // The stack is last in, first out.
+                Node::Product(a, b) => {
+                    if slp.inverse {
+                        stack.push(a.inv());
+                        stack.push(b.inv());
+                    } else {
+                        stack.push(b);
+                        stack.push(a);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Evaluates a word over the generators of the group.
+    ///
+    /// # Panics
+    /// This method will panic if the word refers to a nonexistent generator.
+    pub fn evaluate(&self, word: &[Letter]) -> Permutation<S> {
+        let mut p = Permutation::identity(self.size);
+        for l in word.iter().rev() {
+            let g = &self.generators[l.generator];
+            if l.inverse {
+                g.inv().compose_mut_rhs(&mut p);
+            } else {
+                g.compose_mut_rhs(&mut p);
+            }
+        }
+
+        p
+    }
+
+    /// Returns an iterator over the elements of the group.
+    pub fn elements(&self) -> Elements<'_, S> {
+        Elements {
+            group: self,
+            indices: Some(vec![0; self.levels.len()]),
+        }
+    }
+
+    /// Returns a uniformly random element of the group.
+    pub fn random_element(&self, rng: &mut Rng) -> Permutation<S> {
+        let mut p = Permutation::identity(self.size);
+        for level in self.levels.iter().rev() {
+            let q = level.orbit[rng.index(level.orbit.len())];
+            level.transversal[q]
+                .as_ref()
+                .unwrap()
+                .perm
+                .compose_mut_rhs(&mut p);
+        }
+
+        p
+    }
+}
+
+/// An iterator over the elements of a [`PermGroup`].
+///
+/// Every element can be uniquely written as a product `u₀ u₁ … uₖ`, where each
+/// `uᵢ` is taken from the transversal of the `i`-th level of the stabilizer
+/// chain. We iterate over all of these choices in lexicographic order.
+pub struct Elements<'a, S: OwnedStorage<Inner = usize>> {
+    /// The group we're iterating over.
+    group: &'a PermGroup<S>,
+
+    /// For each level, the index in the orbit of the next transversal element,
+    /// or `None` if we've exhausted the group.
+    indices: Option<Vec<usize>>,
+}
+
+impl<'a, S: OwnedStorage<Inner = usize> + Clone> Iterator for Elements<'a, S> {
+    type Item = Permutation<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let indices = self.indices.as_mut()?;
+        let levels = &self.group.levels;
+
+        let mut p = Permutation::identity(self.group.size);
+        for (level, &i) in levels.iter().zip(indices.iter()).rev() {
+            level.transversal[level.orbit[i]]
+                .as_ref()
+                .unwrap()
+                .perm
+                .compose_mut_rhs(&mut p);
+        }
+
+        // Advances the indices, from the last level to the first.
+        let mut l = levels.len();
+        loop {
+            if l == 0 {
+                self.indices = None;
+                break;
+            }
+
+            l -= 1;
+            indices[l] += 1;
+            if indices[l] < levels[l].orbit.len() {
+                break;
+            }
+
+            indices[l] = 0;
+        }
+
+        Some(p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order() {
+        let size = Size::from_usize(10);
+        assert_eq!(PermGroupD::trivial(size).order(), BigNat::one());
+        assert_eq!(
+            PermGroupD::symmetric(size).order(),
+            BigNat::from(3_628_800u64)
+        );
+        assert_eq!(
+            PermGroupD::alternating(size).order(),
+            BigNat::from(1_814_400u64)
+        );
+        assert_eq!(PermGroupD::dihedral(size).order(), BigNat::from(20u64));
+
+        let s = PermGroupD::symmetric(Size::from_usize(30));
+        assert_eq!(s.order().to_string(), "265252859812191058636308480000000");
+    }
+
+    /// The Mathieu group `M₁₁`, of order 7920.
+    fn mathieu_11() -> PermGroupS<11> {
+        let size = Default::default();
+        PermGroup::new(
+            size,
+            vec![
+                Permutation::from_cycles(size, &[(0..11).collect::<Vec<_>>()]).unwrap(),
+                Permutation::from_cycles(size, &[vec![2, 6, 10, 7], vec![3, 9, 4, 5]]).unwrap(),
+            ],
+        )
+    }
+
+    #[test]
+    fn mathieu() {
+        let m11 = mathieu_11();
+        assert_eq!(m11.order(), BigNat::from(7920u64));
+
+        let size = m11.size();
+        assert!(m11.contains(&m11.generators()[0].inv()));
+        assert!(!m11.contains(&Permutation::from_cycles(size, &[[0, 1]]).unwrap()));
+    }
+
+    #[test]
+    fn factorize() {
+        let m11 = mathieu_11();
+        let mut rng = Rng::new(1);
+
+        for _ in 0..20 {
+            let p = m11.random_element(&mut rng);
+            assert!(m11.contains(&p));
+
+            let word = m11.factorize(&p).unwrap();
+            assert_eq!(m11.evaluate(&word), p);
+        }
+    }
+
+    #[test]
+    fn elements() {
+        let g = PermGroupS::<4>::symmetric(Default::default());
+        let mut elements: Vec<_> = g.elements().map(|p| p.iter().collect::<Vec<_>>()).collect();
+        elements.sort();
+        elements.dedup();
+        assert_eq!(elements.len(), 24);
+
+        assert_eq!(
+            PermGroupS::<4>::trivial(Default::default())
+                .elements()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn with_base() {
+        let size = Size::from_usize(6);
+        let g = PermGroupD::with_base(size, PermGroupD::dihedral(size).generators().to_vec(), &[3]);
+
+        assert_eq!(g.base()[0], 3);
+        assert_eq!(g.basic_orbit(0).len(), 6);
+        assert_eq!(g.basic_stabilizer_generators(1).len(), 1);
+    }
+}
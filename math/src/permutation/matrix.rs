@@ -0,0 +1,103 @@
+//! Implements the conversion between permutations and
+//! [permutation matrices](https://en.wikipedia.org/wiki/Permutation_matrix).
+
+use algebra::Ring;
+
+use super::Permutation;
+use crate::{
+    matrix::SquareMatrix,
+    storage::{OwnedStorage, Storage},
+};
+
+impl<S: Storage<Inner = usize>> Permutation<S> {
+    /// Returns the permutation matrix of the permutation, whose entry at row
+    /// `p[j]` and column `j` is `1`, and which is `0` everywhere else. This
+    /// way, the matrix of `p * q` is the product of the matrices of `p` and
+    /// `q`.
+    ///
+    /// # Panics
+    /// This method will panic if the square of the length of the permutation
+    /// is incompatible with the size of the matrix.
+    pub fn to_matrix<M: OwnedStorage>(&self) -> SquareMatrix<M>
+    where
+        M::Inner: Ring,
+    {
+        SquareMatrix::from_fn(self.len(), |i, j| {
+            if self[j] == i {
+                M::Inner::one()
+            } else {
+                M::Inner::zero()
+            }
+        })
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Permutation<S> {
+    /// Converts a permutation matrix back into a permutation. Returns `None`
+    /// if the matrix isn't a permutation matrix.
+    ///
+    /// # Panics
+    /// This method will panic if the dimension of the matrix is incompatible
+    /// with the size of the permutation.
+    pub fn from_matrix<M: Storage>(m: &SquareMatrix<M>) -> Option<Self>
+    where
+        M::Inner: Ring + PartialEq,
+    {
+        let one = M::Inner::one();
+        let mut entries = Vec::with_capacity(m.dim());
+
+        for j in 0..m.dim() {
+            let mut row = None;
+            for (i, x) in m.col(j).enumerate() {
+                if *x == one && row.is_none() {
+                    row = Some(i);
+                } else if !x.is_zero() {
+                    return None;
+                }
+            }
+
+            entries.push(row?);
+        }
+
+        Self::new(entries.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        matrix::{SquareMatrixD, SquareMatrixS},
+        permutation::{PermutationD, PermutationS},
+        random::Rng,
+        storage::Size,
+    };
+
+    #[test]
+    fn matrix() {
+        let p = PermutationS::new([2, 0, 3, 1].into()).unwrap();
+        let m: SquareMatrixS<i32, 4> = p.to_matrix();
+        assert_eq!(m[(2, 0)], 1);
+        assert_eq!(m[(0, 0)], 0);
+        assert_eq!(PermutationS::from_matrix(&m), Some(p));
+        assert_eq!(m.det_leibniz(), p.sign());
+
+        let mut bad = m;
+        bad[(1, 0)] = 1;
+        assert_eq!(PermutationS::<4>::from_matrix(&bad), None);
+    }
+
+    #[test]
+    fn sign() {
+        let mut rng = Rng::new(11);
+        let size = Size::from_usize(5);
+        for _ in 0..10 {
+            let p = PermutationD::random(size, &mut rng);
+            let q = PermutationD::random(size, &mut rng);
+            let (mp, mq): (SquareMatrixD<i64>, SquareMatrixD<i64>) = (p.to_matrix(), q.to_matrix());
+
+            assert_eq!((&p * &q).to_matrix(), &mp * &mq);
+            assert_eq!(mp.det_leibniz(), p.sign::<i64>());
+            assert_eq!((&p * &q).sign::<i64>(), p.sign::<i64>() * q.sign::<i64>());
+        }
+    }
+}
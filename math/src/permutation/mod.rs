@@ -0,0 +1,563 @@
+mod combinatorics;
+mod conjugacy;
+mod group;
+mod matrix;
+
+pub use conjugacy::*;
+pub use group::*;
+
+use std::{
+    cmp::Ordering,
+    iter::{self, FromIterator},
+    ops::{Index, Mul},
+};
+
+use algebra::Ring;
+
+use crate::{
+    random::Rng,
+    storage::{
+        ArrayStorageE, ContiguousStorage, ContiguousStorageMut, Iter, IterMut, OwnedStorage, Size,
+        Storage, StorageMut, VecStorageE,
+    },
+};
+
+/// The parity of a permutation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Parity {
+    /// Even parity.
+    Even,
+
+    /// Odd parity.
+    Odd,
+}
+
+impl Parity {
+    /// Flips the parity of `self`.
+    #[must_use = "use `flip_mut` to flip the parity in place"]
+    pub fn flip(&self) -> Self {
+        match self {
+            Self::Even => Self::Odd,
+            Self::Odd => Self::Even,
+        }
+    }
+
+    /// Flips the parity of `self` in place.
+    pub fn flip_mut(&mut self) {
+        *self = self.flip();
+    }
+
+    /// Returns the sign associated to the parity, that is, `1` if even and
+    /// `-1` if odd, in any ring.
+    pub fn sign<T: Ring>(&self) -> T {
+        match self {
+            Self::Even => T::one(),
+            Self::Odd => T::one().neg(),
+        }
+    }
+}
+
+/// Represents a permutation. This is stored as some storage of `usize` whose
+/// entries must be numbers from 0 to the length of the permutation minus 1,
+/// all pairwise different.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Permutation<S: Storage<Inner = usize>>(S);
+
+/// A statically-sized permutation, backed by an [`ArrayStorage`].
+pub type PermutationS<const N: usize> = Permutation<ArrayStorageE<usize, N>>;
+
+/// A dynamically-sized permutation, backed by an [`VecStorage`].
+pub type PermutationD = Permutation<VecStorageE<usize>>;
+
+impl<S: OwnedStorage<Inner = usize>> FromIterator<usize> for Permutation<S> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<S: Storage<Inner = usize>> Index<usize> for Permutation<S> {
+    type Output = usize;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<S: ContiguousStorage<Inner = usize>> AsRef<[usize]> for Permutation<S> {
+    fn as_ref(&self) -> &[usize] {
+        self.0.borrow()
+    }
+}
+
+impl<S: ContiguousStorageMut<Inner = usize>> AsMut<[usize]> for Permutation<S> {
+    fn as_mut(&mut self) -> &mut [usize] {
+        self.0.borrow_mut()
+    }
+}
+
+impl<S: Storage<Inner = usize>> Permutation<S> {
+    /// Returns the length of the permutation.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the permutation is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the size of the permutation.
+    pub fn size(&self) -> Size<S> {
+        self.0.size()
+    }
+
+    /// Returns the underlying storage of the permutation.
+    pub fn into_storage(self) -> S {
+        self.0
+    }
+
+    /// Returns whether the permutation is the identity.
+    pub fn is_identity(&self) -> bool {
+        self.iter().enumerate().all(|(i, v)| i == v)
+    }
+
+    /// Returns the cycles of the permutation, including fixed points. Every
+    /// cycle starts with its smallest entry, and they're sorted by it.
+    pub fn cycles(&self) -> Vec<Vec<usize>> {
+        let mut checked = vec![false; self.len()];
+        let mut cycles = Vec::new();
+
+        for i in 0..self.len() {
+            if !checked[i] {
+                let mut cycle = vec![i];
+                checked[i] = true;
+
+                let mut j = self[i];
+                while j != i {
+                    cycle.push(j);
+                    checked[j] = true;
+                    j = self[j];
+                }
+
+                cycles.push(cycle);
+            }
+        }
+
+        cycles
+    }
+
+    /// Returns the [cycle type](https://en.wikipedia.org/wiki/Cycle_type) of
+    /// the permutation. That is, the lengths of its cycles, including fixed
+    /// points, in non-increasing order.
+    pub fn cycle_type(&self) -> Vec<usize> {
+        let mut lens: Vec<_> = self.cycles().iter().map(Vec::len).collect();
+        lens.sort_unstable_by(|a, b| b.cmp(a));
+        lens
+    }
+
+    /// Composes `self` with another permutation and assigns to it.
+    pub fn compose_mut_rhs<A: StorageMut<Inner = usize>>(&self, p: &mut Permutation<A>) {
+        // Safety: composing two permutations gives a permutation.
+        for v in unsafe { p.iter_mut() } {
+            *v = self[*v];
+        }
+    }
+
+    /// Returns an iterator over the entries of the permutation.
+    pub fn iter(&self) -> iter::Copied<Iter<'_, S>> {
+        self.0.iter().copied()
+    }
+
+    /// Returns whether the entries of `data`, read in the order given by the
+    /// permutation, are sorted according to some comparison function.
+    ///
+    /// In particular, this holds for any permutation returned by
+    /// [`argsort_by`](Self::argsort_by) on the same data and function.
+    pub fn is_sorted_by<A: Storage, F: FnMut(&A::Inner, &A::Inner) -> Ordering>(
+        &self,
+        data: &A,
+        mut cmp: F,
+    ) -> bool {
+        (1..self.len()).all(|i| cmp(&data[self[i - 1]], &data[self[i]]).is_le())
+    }
+
+    /// Returns whether the entries of `data`, read in the order given by the
+    /// permutation, are sorted.
+    pub fn is_sorted<A: Storage>(&self, data: &A) -> bool
+    where
+        A::Inner: Ord,
+    {
+        self.is_sorted_by(data, Ord::cmp)
+    }
+}
+
+impl<S: StorageMut<Inner = usize>> Permutation<S> {
+    /// Swaps two values in the permutation.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.0.swap(i, j);
+    }
+
+    /// Returns a mutable iterator over the entries of the permutation.
+    ///
+    /// # Safety
+    /// After the iterator is called, you must ensure that what remains is a
+    /// valid permutation.
+    pub unsafe fn iter_mut(&mut self) -> IterMut<'_, S> {
+        self.0.iter_mut()
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Permutation<S> {
+    /// Initializes a new permutation. No invariants are checked.
+    ///
+    /// # Safety
+    /// All entries of the permutation must be pairwise distinct integers, all
+    /// less than the length of the permutation.
+    pub unsafe fn new_unchecked(s: S) -> Self {
+        Self(s)
+    }
+
+    /// Initializes a new permutation. Will check that the entries are valid. To
+    /// forgo this expensive check, use [`new_unchecked`].
+    pub fn new(s: S) -> Option<Self> {
+        // We check that there are no repeat entries, nor entries out of bounds.
+        let mut checked = vec![false; s.len()];
+        for &v in s.iter() {
+            let entry = checked.get_mut(v)?;
+
+            if *entry {
+                return None;
+            }
+
+            *entry = true;
+        }
+
+        // Safety: we literally just checked the invariants!
+        unsafe { Some(Self::new_unchecked(s)) }
+    }
+
+    /// Returns the identity permutation.
+    pub fn identity(size: Size<S>) -> Self {
+        (0..size.value()).collect()
+    }
+
+    /// Builds a permutation from its cycles, so that every entry of a cycle is
+    /// mapped to the next one. Returns `None` if an entry is out of bounds or
+    /// repeated.
+    pub fn from_cycles<C: AsRef<[usize]>>(size: Size<S>, cycles: &[C]) -> Option<Self> {
+        let mut entries: Vec<_> = (0..size.value()).collect();
+        let mut checked = vec![false; size.value()];
+
+        for cycle in cycles {
+            let cycle = cycle.as_ref();
+            for (i, &v) in cycle.iter().enumerate() {
+                let entry = checked.get_mut(v)?;
+
+                if *entry {
+                    return None;
+                }
+
+                *entry = true;
+                entries[v] = cycle[(i + 1) % cycle.len()];
+            }
+        }
+
+        // Safety: every cycle is a bijection on its entries.
+        unsafe { Some(Self::new_unchecked(entries.into_iter().collect())) }
+    }
+
+    /// Returns the inverse of a permutation.
+    pub fn inv(&self) -> Self {
+        let mut inv = vec![0; self.len()];
+        for (i, v) in self.iter().enumerate() {
+            inv[v] = i;
+        }
+
+        // Safety: the inverse of a permutation is a permutation.
+        unsafe { Self::new_unchecked(inv.into_iter().collect()) }
+    }
+
+    /// Returns a uniformly random permutation of a given size.
+    pub fn random(size: Size<S>, rng: &mut Rng) -> Self {
+        let mut p = Self::identity(size);
+        // Safety: shuffling a permutation gives a permutation.
+        rng.shuffle(&mut p.0);
+        p
+    }
+
+    /// Composes two permutations together.
+    pub fn compose<A: Storage<Inner = usize>, B: Storage<Inner = usize>>(
+        p: &Permutation<A>,
+        q: &Permutation<B>,
+    ) -> Self {
+        (0..p.len()).map(|idx| p[q[idx]]).collect()
+    }
+
+    /// Returns the parity of a permutation.
+    pub fn parity(&self) -> Parity {
+        let mut checked = vec![false; self.len()];
+        let mut parity = Parity::Even;
+
+        for i in 0..self.len() {
+            if !checked[i] {
+                checked[i] = true;
+
+                let mut j = self[i];
+                let mut len = 1;
+                while j != i {
+                    checked[j] = true;
+                    j = self[j];
+                    len += 1;
+                }
+
+                if len % 2 == 0 {
+                    parity.flip_mut();
+                }
+            }
+        }
+
+        parity
+    }
+
+    /// Returns the [sign](https://en.wikipedia.org/wiki/Parity_of_a_permutation)
+    /// of a permutation, as `1` or `-1` in any ring.
+    pub fn sign<T: Ring>(&self) -> T {
+        self.parity().sign()
+    }
+}
+
+/// Sorting permutations.
+impl<S: OwnedStorage<Inner = usize>> Permutation<S> {
+    /// Sorts the indices of `data` according to some comparison function.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    fn sort_indices<A: Storage, F: FnMut(&A::Inner, &A::Inner) -> Ordering>(
+        data: &A,
+        stable: bool,
+        mut cmp: F,
+    ) -> Self {
+        let size = Size::<S>::from_usize(data.len());
+        let mut indices: Vec<_> = (0..size.value()).collect();
+        let cmp = |&i: &usize, &j: &usize| cmp(&data[i], &data[j]);
+
+        if stable {
+            indices.sort_by(cmp);
+        } else {
+            indices.sort_unstable_by(cmp);
+        }
+
+        // Safety: we've only rearranged the indices.
+        unsafe { Self::new_unchecked(indices.into_iter().collect()) }
+    }
+
+    /// Returns the permutation that stably sorts `data`. That is, the `i`-th
+    /// entry of the permutation is the index of the `i`-th smallest value.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn argsort<A: Storage>(data: &A) -> Self
+    where
+        A::Inner: Ord,
+    {
+        Self::argsort_by(data, Ord::cmp)
+    }
+
+    /// Returns the permutation that stably sorts `data` according to some
+    /// comparison function.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn argsort_by<A: Storage, F: FnMut(&A::Inner, &A::Inner) -> Ordering>(
+        data: &A,
+        cmp: F,
+    ) -> Self {
+        Self::sort_indices(data, true, cmp)
+    }
+
+    /// Returns the permutation that stably sorts `data` according to some key
+    /// extraction function.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn argsort_by_key<A: Storage, K: Ord, F: FnMut(&A::Inner) -> K>(
+        data: &A,
+        mut f: F,
+    ) -> Self {
+        Self::argsort_by(data, |x, y| f(x).cmp(&f(y)))
+    }
+
+    /// Returns a permutation that sorts `data`, not necessarily preserving the
+    /// order of equal elements.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn argsort_unstable<A: Storage>(data: &A) -> Self
+    where
+        A::Inner: Ord,
+    {
+        Self::argsort_unstable_by(data, Ord::cmp)
+    }
+
+    /// Returns a permutation that sorts `data` according to some comparison
+    /// function, not necessarily preserving the order of equal elements.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn argsort_unstable_by<A: Storage, F: FnMut(&A::Inner, &A::Inner) -> Ordering>(
+        data: &A,
+        cmp: F,
+    ) -> Self {
+        Self::sort_indices(data, false, cmp)
+    }
+
+    /// Returns a permutation that sorts `data` according to some key extraction
+    /// function, not necessarily preserving the order of equal elements.
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn argsort_unstable_by_key<A: Storage, K: Ord, F: FnMut(&A::Inner) -> K>(
+        data: &A,
+        mut f: F,
+    ) -> Self {
+        Self::argsort_unstable_by(data, |x, y| f(x).cmp(&f(y)))
+    }
+
+    /// Returns the ranks of the entries of `data`. That is, the `i`-th entry of
+    /// the permutation is the position the `i`-th value would have if `data`
+    /// were stably sorted.
+    ///
+    /// This is the inverse of [`argsort`](Self::argsort).
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn ranks<A: Storage>(data: &A) -> Self
+    where
+        A::Inner: Ord,
+    {
+        Self::ranks_by(data, Ord::cmp)
+    }
+
+    /// Returns the ranks of the entries of `data` according to some comparison
+    /// function.
+    ///
+    /// This is the inverse of [`argsort_by`](Self::argsort_by).
+    ///
+    /// # Panics
+    /// This method will panic if the length of `data` is incompatible with the
+    /// size of the permutation.
+    pub fn ranks_by<A: Storage, F: FnMut(&A::Inner, &A::Inner) -> Ordering>(
+        data: &A,
+        cmp: F,
+    ) -> Self {
+        Self::argsort_by(data, cmp).inv()
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Mul for Permutation<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a, S: OwnedStorage<Inner = usize>> Mul<&'a Self> for Permutation<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a Self) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Mul<Permutation<S>> for &Permutation<S> {
+    type Output = Permutation<S>;
+
+    fn mul(self, rhs: Permutation<S>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Mul<&Permutation<S>> for &Permutation<S> {
+    type Output = Permutation<S>;
+
+    fn mul(self, rhs: &Permutation<S>) -> Self::Output {
+        Permutation::compose(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose() {
+        let p = PermutationS::new([0, 2, 1, 3].into()).unwrap();
+        assert_eq!(p.parity(), Parity::Odd);
+        assert_eq!(p * p, PermutationS::identity(Default::default()));
+    }
+
+    #[test]
+    fn cycles() {
+        let p = PermutationS::<6>::from_cycles(Default::default(), &[vec![4, 1], vec![0, 5, 2]])
+            .unwrap();
+        assert_eq!(p.iter().collect::<Vec<_>>(), vec![5, 4, 0, 3, 1, 2]);
+        assert_eq!(p.cycles(), vec![vec![0, 5, 2], vec![1, 4], vec![3]]);
+        assert_eq!(p.cycle_type(), vec![3, 2, 1]);
+        assert_eq!(p, crate::perm!((4 1)(0 5 2); 6));
+        assert_eq!(p, crate::perm![5, 4, 0, 3, 1, 2]);
+        assert!(PermutationS::<6>::from_cycles(Default::default(), &[[0, 1, 0]]).is_none());
+    }
+
+    #[test]
+    fn inv() {
+        let p = PermutationS::new([2, 0, 3, 1].into()).unwrap();
+        assert_eq!(p * p.inv(), PermutationS::identity(Default::default()));
+        assert_eq!(p.inv() * p, PermutationS::identity(Default::default()));
+    }
+
+    #[test]
+    fn argsort() {
+        let data = ArrayStorageE::from([3, 1, 4, 1, 5]);
+        let p = PermutationS::argsort(&data);
+        assert_eq!(p, PermutationS::new([1, 3, 0, 2, 4].into()).unwrap());
+        assert!(p.is_sorted(&data));
+
+        let q = PermutationS::<5>::argsort_unstable(&data);
+        assert!(q.is_sorted(&data));
+        assert!(!PermutationS::<5>::identity(Default::default()).is_sorted(&data));
+
+        let r = PermutationS::ranks(&data);
+        assert_eq!(r, PermutationS::new([2, 0, 3, 1, 4].into()).unwrap());
+        assert_eq!(r, p.inv());
+    }
+
+    #[test]
+    fn argsort_by_key() {
+        let data: VecStorageE<_> = vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')]
+            .into_iter()
+            .collect();
+        let p = PermutationD::argsort_by_key(&data, |&(k, _)| k);
+        assert_eq!(p.iter().collect::<Vec<_>>(), vec![1, 3, 0, 2]);
+        assert!(p.is_sorted_by(&data, |x, y| x.0.cmp(&y.0)));
+
+        let q = PermutationD::argsort_by(&data, |x, y| y.cmp(x));
+        assert_eq!(q.iter().collect::<Vec<_>>(), vec![2, 0, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn argsort_size_mismatch() {
+        let data = ArrayStorageE::from([0, 1, 2]);
+        PermutationS::<4>::argsort(&data);
+    }
+}
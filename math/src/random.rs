@@ -0,0 +1,112 @@
+//! Declares a small, seedable pseudorandom number generator.
+
+use crate::storage::StorageMut;
+
+/// A pseudorandom number generator, implementing the
+/// [xoshiro256**](https://prng.di.unimi.it/) algorithm.
+///
+/// This generator is fast and has good statistical properties, but it is
+/// **not** cryptographically secure.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng([u64; 4]);
+
+impl Default for Rng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl Rng {
+    /// Initializes a new generator from a given seed. The state is filled in
+    /// using SplitMix64, as recommended by the authors of the algorithm.
+    pub fn new(mut seed: u64) -> Self {
+        let mut state = [0; 4];
+        for s in &mut state {
+            seed = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = seed;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *s = z ^ (z >> 31);
+        }
+
+        Self(state)
+    }
+
+    /// Returns a uniformly random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        let s = &mut self.0;
+        let res = s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = s[1] << 17;
+
+        s[2] ^= s[0];
+        s[3] ^= s[1];
+        s[1] ^= s[2];
+        s[0] ^= s[3];
+        s[2] ^= t;
+        s[3] = s[3].rotate_left(45);
+
+        res
+    }
+
+    /// Returns a uniformly random number less than `n`.
+    ///
+    /// # Panics
+    /// This method will panic if `n` is zero.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert_ne!(n, 0, "empty range");
+
+        // Lemire's method, rejecting the values that would bias the result.
+        let threshold = n.wrapping_neg() % n;
+        loop {
+            let prod = self.next_u64() as u128 * n as u128;
+            if prod as u64 >= threshold {
+                return (prod >> 64) as u64;
+            }
+        }
+    }
+
+    /// Returns a uniformly random index less than `n`.
+    ///
+    /// # Panics
+    /// This method will panic if `n` is zero.
+    pub fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    /// Shuffles the entries of a storage uniformly at random.
+    pub fn shuffle<S: StorageMut + ?Sized>(&mut self, s: &mut S) {
+        for i in (1..s.len()).rev() {
+            let j = self.index(i + 1);
+            s.swap(i, j);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{ArrayStorageE, Storage};
+
+    #[test]
+    fn below() {
+        let mut rng = Rng::new(42);
+        let mut counts = [0; 6];
+        for _ in 0..6000 {
+            counts[rng.index(6)] += 1;
+        }
+
+        assert!(counts.iter().all(|&c| c > 850 && c < 1150));
+        assert_eq!(Rng::new(42).next_u64(), Rng::new(42).next_u64());
+    }
+
+    #[test]
+    fn shuffle() {
+        let mut rng = Rng::new(7);
+        let mut s = ArrayStorageE::from([0, 1, 2, 3, 4, 5, 6, 7]);
+        rng.shuffle(&mut s);
+
+        let mut v: Vec<_> = s.iter().copied().collect();
+        v.sort_unstable();
+        assert_eq!(v, (0..8).collect::<Vec<_>>());
+    }
+}
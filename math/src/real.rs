@@ -0,0 +1,252 @@
+//! Implements the algebraic structure of the [`R`] wrapper.
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use algebra::{
+    Add, Associative, Automorphism, BinOp, BinOpAssign, Bws, Commutative, Conj, DivisionRing,
+    Endomorphism, Flexible, Group, Injective, Involution, LeftAlternative, LeftQuasigroup,
+    LeftUnital, Magma, Medial, Mul, Neg, PowAssociative, Rec, RightAlternative, RightQuasigroup,
+    RightUnital, Ring, Sub, Surjective, UnOp, UnOpAssign,
+};
+
+use crate::R;
+
+/// Implements a binary operation on a wrapper of real numbers. Non-finite
+/// results return an error.
+macro_rules! impl_real_bin_op {
+    ($type:ty, $op:ty, $sym:tt) => {
+        impl BinOp<$op> for R<$type> {
+            type Output = Self;
+            type Err = ();
+
+            fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+                let res = self.0 $sym rhs.0;
+                res.is_finite().then_some(R(res)).ok_or(())
+            }
+        }
+
+        impl BinOpAssign<$op> for R<$type> {
+            type Err = ();
+
+            fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+                *self = BinOp::<$op>::bin_op(self, rhs)?;
+                Ok(())
+            }
+
+            fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+                *rhs = BinOp::<$op>::bin_op(self, rhs)?;
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implements the algebraic structure of a wrapper of real numbers, together
+/// with the tolerance used to compare them.
+macro_rules! impl_real {
+    ($($type:ty: $tol:expr),*) => {
+        $(
+            impl R<$type> {
+                /// The relative tolerance used for comparisons.
+                pub const TOLERANCE: $type = $tol;
+            }
+
+            /// Compares two reals using relative differences. Values close to
+            /// zero are compared using absolute differences instead.
+            impl PartialEq for R<$type> {
+                fn eq(&self, other: &Self) -> bool {
+                    let scale = self.0.abs().max(other.0.abs()).max(1.0);
+                    (self.0 - other.0).abs() <= Self::TOLERANCE * scale
+                }
+            }
+
+            impl PartialOrd for R<$type> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    if self == other {
+                        Some(Ordering::Equal)
+                    } else {
+                        self.0.partial_cmp(&other.0)
+                    }
+                }
+            }
+
+            impl Display for R<$type> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.fmt(f)
+                }
+            }
+
+            impl FromStr for R<$type> {
+                type Err = ();
+
+                /// Parses a float, or returns an error if it's invalid or not
+                /// finite.
+                fn from_str(s: &str) -> Result<Self, ()> {
+                    let x: $type = s.parse().map_err(|_| ())?;
+                    x.is_finite().then_some(R(x)).ok_or(())
+                }
+            }
+
+            impl From<$type> for R<$type> {
+                fn from(x: $type) -> Self {
+                    Self(x)
+                }
+            }
+
+            impl_real_bin_op!($type, Add, +);
+            impl_real_bin_op!($type, Sub, -);
+            impl_real_bin_op!($type, Mul, *);
+            impl_real_bin_op!($type, algebra::Div, /);
+
+            impl UnOp<Neg> for R<$type> {
+                type Output = Self;
+                type Err = ();
+
+                fn un_op(&self) -> Result<Self, ()> {
+                    Ok(R(-self.0))
+                }
+            }
+
+            impl UnOpAssign<Neg> for R<$type> {
+                type Err = ();
+
+                fn un_op_assign(&mut self) -> Result<(), ()> {
+                    self.0 = -self.0;
+                    Ok(())
+                }
+            }
+
+            impl UnOp<Rec> for R<$type> {
+                type Output = Self;
+                type Err = ();
+
+                fn un_op(&self) -> Result<Self, ()> {
+                    let res = self.0.recip();
+                    res.is_finite().then_some(R(res)).ok_or(())
+                }
+            }
+
+            impl UnOpAssign<Rec> for R<$type> {
+                type Err = ();
+
+                fn un_op_assign(&mut self) -> Result<(), ()> {
+                    *self = UnOp::<Rec>::un_op(self)?;
+                    Ok(())
+                }
+            }
+
+            impl UnOp<Conj> for R<$type> {
+                type Output = Self;
+                type Err = ();
+
+                fn un_op(&self) -> Result<Self, ()> {
+                    Ok(*self)
+                }
+            }
+
+            impl UnOpAssign<Conj> for R<$type> {
+                type Err = ();
+
+                fn un_op_assign(&mut self) -> Result<(), ()> {
+                    Ok(())
+                }
+            }
+
+            impl Endomorphism<Conj> for R<$type> {}
+            impl Surjective<Conj> for R<$type> {}
+            impl Automorphism<Conj> for R<$type> {}
+            impl Involution<Conj> for R<$type> {}
+
+            impl Injective<Conj> for R<$type> {
+                type Inv = Conj;
+            }
+
+            impl Magma<Add> for R<$type> {}
+            impl Magma<Sub> for R<$type> {}
+            impl Magma<Mul> for R<$type> {}
+
+            impl PowAssociative<Add> for R<$type> {}
+            impl PowAssociative<Mul> for R<$type> {}
+            impl Associative<Add> for R<$type> {}
+            impl Associative<Mul> for R<$type> {}
+            impl Commutative<Add> for R<$type> {}
+            impl Commutative<Mul> for R<$type> {}
+            impl Flexible<Add> for R<$type> {}
+            impl Flexible<Mul> for R<$type> {}
+            impl LeftAlternative<Add> for R<$type> {}
+            impl LeftAlternative<Mul> for R<$type> {}
+            impl RightAlternative<Add> for R<$type> {}
+            impl RightAlternative<Mul> for R<$type> {}
+            impl Medial<Add> for R<$type> {}
+            impl Medial<Mul> for R<$type> {}
+
+            impl LeftUnital<Add> for R<$type> {
+                fn left_id() -> Self {
+                    R(0.0)
+                }
+            }
+
+            impl RightUnital<Add> for R<$type> {
+                fn right_id() -> Self {
+                    R(0.0)
+                }
+            }
+
+            impl LeftUnital<Mul> for R<$type> {
+                fn left_id() -> Self {
+                    R(1.0)
+                }
+            }
+
+            impl RightUnital<Mul> for R<$type> {
+                fn right_id() -> Self {
+                    R(1.0)
+                }
+            }
+
+            impl LeftQuasigroup<Add> for R<$type> {
+                type LInv = Bws<Sub>;
+            }
+
+            impl RightQuasigroup<Add> for R<$type> {
+                type RInv = Sub;
+            }
+
+            impl Group<Add> for R<$type> {
+                type Inv = Neg;
+            }
+
+            impl Ring for R<$type> {}
+            impl DivisionRing for R<$type> {}
+        )*
+    };
+}
+
+impl_real!(f32: 1e-4, f64: 1e-10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use algebra::Field;
+
+    /// Asserts that a type is a field.
+    fn assert_field<T: Field>() {}
+
+    #[test]
+    fn field() {
+        assert_field::<R<f64>>();
+
+        let (a, b, c) = (R(0.1), R(0.2), R(0.3));
+        assert_eq!(a.add(&b), c);
+        assert!(Ring::test_left_distrib(&a, &b, &c));
+        assert_eq!(a.sub(&b), R(-0.1));
+        assert_eq!(R(4.0).div(&R(8.0)), Some(R(0.5)));
+        assert_eq!(R(4.0).div(&R(0.0)), None);
+        assert!(R(3.0).test_rec() && R(0.0).test_rec());
+        assert!(R(1e-20) == R(0.0) && R(1e20) != R(1.0001e20));
+    }
+}
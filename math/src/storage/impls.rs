@@ -17,7 +17,7 @@ use super::*;
 /// storage of this size. This isn't a problem if the storage is dynamically
 /// sized. For statically sized storages, you can use the `Default`
 /// implementation to initialize the size.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Size<S: Storage + ?Sized> {
     /// The numeric value for the size.
     value: usize,
@@ -26,6 +26,16 @@ pub struct Size<S: Storage + ?Sized> {
     _phantom: PhantomData<S>,
 }
 
+// We implement these manually, since the derive macros would require that `S`
+// also implements these traits.
+impl<S: Storage + ?Sized> Clone for Size<S> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: Storage + ?Sized> Copy for Size<S> {}
+
 impl<S: Storage> From<Size<S>> for usize {
     fn from(s: Size<S>) -> Self {
         s.value
@@ -187,7 +197,7 @@ impl<T> Storage for Entry<T> {
     const SIZE: Option<usize> = Some(1);
 
     fn get(&self, index: usize) -> Option<&Self::Inner> {
-        (index == 0).then(|| &self.0)
+        (index == 0).then_some(&self.0)
     }
 
     fn len(&self) -> usize {
@@ -197,7 +207,7 @@ impl<T> Storage for Entry<T> {
 
 unsafe impl<T> StorageMut for Entry<T> {
     fn get_mut(&mut self, index: usize) -> Option<&mut Self::Inner> {
-        (index == 0).then(move || &mut self.0)
+        (index == 0).then_some(&mut self.0)
     }
 }
 
@@ -275,7 +285,7 @@ unsafe impl<'a, S: StorageMut> StorageMut for Mut<'a, S> {
 
 impl<'a, S: ContiguousStorage> Borrow<[S::Inner]> for Mut<'a, S> {
     fn borrow(&self) -> &[S::Inner] {
-        (&*self.0).borrow()
+        (*self.0).borrow()
     }
 }
 
@@ -333,16 +343,14 @@ impl<S: StackStorage, const N: usize> FromIterator<S::Inner> for ArrayStorage<S,
 type IntoIterFn<T> = fn(T) -> <T as IntoIterator>::IntoIter;
 
 /// The type of a flattened iterator over inner iterators.
-type FlatIntoIter<I, S> = iter::Flatten<iter::Map<I, IntoIterFn<S>>>;
+type FlatIntoIter<I, S> = iter::FlatMap<I, <S as IntoIterator>::IntoIter, IntoIterFn<S>>;
 
 impl<S: StackStorage, const N: usize> IntoIterator for ArrayStorage<S, N> {
     type Item = S::Inner;
     type IntoIter = FlatIntoIter<array::IntoIter<S, N>, S>;
 
     fn into_iter(self) -> Self::IntoIter {
-        array::IntoIter::new(self.0)
-            .map(IntoIterator::into_iter as IntoIterFn<S>)
-            .flatten()
+        IntoIterator::into_iter(self.0).flat_map(IntoIterator::into_iter as IntoIterFn<S>)
     }
 }
 
@@ -378,7 +386,7 @@ impl<S: StackStorage, const N: usize> Storage for ArrayStorage<S, N> {
     }
 
     fn len(&self) -> usize {
-        N
+        Self::SIZE_U
     }
 }
 
@@ -395,7 +403,7 @@ unsafe impl<S: StackStorage, const N: usize> StackStorage for ArrayStorage<S, N>
 /// This type is `#[repr(C)]`, so initializing it with two stack storages of
 /// type `T` will give another one.
 #[repr(C)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, StackStorage)]
 pub struct Join<A: StackStorage, B: StackStorage>(A, B);
 
 impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> Join<A, B> {
@@ -405,77 +413,6 @@ impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> Join<A, B> {
     }
 }
 
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> Index<usize> for Join<A, B> {
-    type Output = T;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        self._index(index)
-    }
-}
-
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> IndexMut<usize> for Join<A, B> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self._index_mut(index)
-    }
-}
-
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> Borrow<[T]> for Join<A, B> {
-    fn borrow(&self) -> &[T] {
-        self._borrow()
-    }
-}
-
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> BorrowMut<[T]> for Join<A, B> {
-    fn borrow_mut(&mut self) -> &mut [T] {
-        self._borrow_mut()
-    }
-}
-
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> FromIterator<T> for Join<A, B> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        Self::_from_iter(iter)
-    }
-}
-
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> IntoIterator for Join<A, B> {
-    type Item = T;
-    type IntoIter = iter::Chain<A::IntoIter, B::IntoIter>;
-
-    fn into_iter(self) -> Self::IntoIter {
-        self.0.into_iter().chain(self.1.into_iter())
-    }
-}
-
-const fn add(a: Option<usize>, b: Option<usize>) -> Option<usize> {
-    if let (Some(a), Some(b)) = (a, b) {
-        Some(a + b)
-    } else {
-        None
-    }
-}
-
-impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> Storage for Join<A, B> {
-    type Inner = T;
-
-    const SIZE: Option<usize> = add(A::SIZE, B::SIZE);
-
-    fn get(&self, index: usize) -> Option<&Self::Inner> {
-        self._get(index)
-    }
-
-    fn len(&self) -> usize {
-        Self::SIZE_U
-    }
-}
-
-unsafe impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> StorageMut for Join<A, B> {
-    fn get_mut(&mut self, index: usize) -> Option<&mut Self::Inner> {
-        self._get_mut(index)
-    }
-}
-
-unsafe impl<T, A: StackStorage<Inner = T>, B: StackStorage<Inner = T>> StackStorage for Join<A, B> {}
-
 #[repr(transparent)]
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 /// A heap-allocated storage. Is backed by a `Vec`.
@@ -565,8 +502,7 @@ impl<S: StackStorage> IntoIterator for VecStorage<S> {
     fn into_iter(self) -> Self::IntoIter {
         self.0
             .into_iter()
-            .map(IntoIterator::into_iter as IntoIterFn<S>)
-            .flatten()
+            .flat_map(IntoIterator::into_iter as IntoIterFn<S>)
     }
 }
 
@@ -610,8 +546,8 @@ impl<T: StackStorage, A: OwnedStorage<Inner = T>, B: OwnedStorage<Inner = T>> Jo
 {
     fn merge(a: A, b: B) -> Self {
         let mut vec = VecStorage::with_capacity(a.len() + b.len());
-        vec.0.extend(a.into_iter());
-        vec.0.extend(b.into_iter());
+        vec.0.extend(a);
+        vec.0.extend(b);
         vec
     }
 }
@@ -637,4 +573,43 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn derive() {
+        /// A color, which doubles as a storage of its channels.
+        #[repr(C)]
+        #[derive(Debug, PartialEq, StackStorage)]
+        struct Rgb {
+            r: Entry<u8>,
+            g: ArrayStorage<Entry<u8>, 2>,
+        }
+
+        assert_eq!(Rgb::SIZE, Some(3));
+        let mut c: Rgb = vec![1, 2, 3].into_iter().collect();
+        c[2] = 4;
+        assert_eq!(c.as_slice(), &[1, 2, 4]);
+        assert_eq!(c.g, ArrayStorage([Entry(2), Entry(4)]));
+        assert_eq!(c.into_iter().collect::<Vec<_>>(), vec![1, 2, 4]);
+    }
+
+    #[test]
+    fn derive_repeated() {
+        /// A pair of entries of the same type.
+        #[repr(C)]
+        #[derive(Debug, PartialEq, StackStorage)]
+        struct Pair(Entry<u32>, Entry<u32>);
+
+        /// A generic triple, whose last two fields share a type.
+        #[repr(C)]
+        #[derive(Debug, PartialEq, StackStorage)]
+        struct Triple<T>(Entry<T>, ArrayStorageE<T, 1>, ArrayStorageE<T, 1>);
+
+        let p: Pair = vec![1, 2].into_iter().collect();
+        assert_eq!(p, Pair(Entry(1), Entry(2)));
+        assert_eq!(p.as_slice(), &[1, 2]);
+
+        let t: Triple<u8> = vec![1, 2, 3].into_iter().collect();
+        assert_eq!(Triple::<u8>::SIZE, Some(3));
+        assert_eq!(t.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
 }
@@ -3,6 +3,59 @@
 pub mod impls;
 pub use impls::*;
 
+/// Derives [`StackStorage`](trait@StackStorage) for a `#[repr(C)]` or
+/// `#[repr(transparent)]` struct whose fields are stack storages of the same
+/// type, along with the traits it requires.
+///
+/// ```
+/// use math::storage::{ArrayStorageE, Entry, StackStorage, Storage};
+///
+/// #[repr(C)]
+/// #[derive(StackStorage)]
+/// struct Pair<T>(Entry<T>, ArrayStorageE<T, 2>);
+///
+/// let p: Pair<u8> = (1..=3).collect();
+/// assert_eq!(p.len(), 3);
+/// assert_eq!(p[1], 2);
+/// ```
+///
+/// The fields must have the same inner type, and the layout must be specified.
+///
+/// ```compile_fail
+/// use math::storage::{Entry, StackStorage};
+///
+/// #[repr(C)]
+/// #[derive(StackStorage)]
+/// struct Mixed(Entry<u8>, Entry<u16>);
+/// ```
+///
+/// ```compile_fail
+/// use math::storage::{Entry, StackStorage};
+///
+/// #[derive(StackStorage)]
+/// struct Unspecified(Entry<u8>, Entry<u8>);
+/// ```
+///
+/// Modifiers such as `packed` or `align` are rejected, since they would break
+/// the layout of an array.
+///
+/// ```compile_fail
+/// use math::storage::{Entry, StackStorage};
+///
+/// #[repr(C, packed)]
+/// #[derive(StackStorage)]
+/// struct Packed(Entry<u32>, Entry<u32>);
+/// ```
+///
+/// ```compile_fail
+/// use math::storage::{Entry, StackStorage};
+///
+/// #[repr(C, align(64))]
+/// #[derive(StackStorage)]
+/// struct Aligned(Entry<u32>, Entry<u32>);
+/// ```
+pub use macros::StackStorage;
+
 use core::slice;
 use std::{
     borrow::{Borrow, BorrowMut},
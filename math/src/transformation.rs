@@ -0,0 +1,455 @@
+//! Implements [transformations](https://en.wikipedia.org/wiki/Transformation_(function))
+//! of a finite set, which together form the
+//! [full transformation monoid](https://en.wikipedia.org/wiki/Full_transformation_semigroup).
+
+use std::{
+    convert::TryFrom,
+    iter::{self, FromIterator},
+    ops::{Index, Mul},
+};
+
+use algebra::{Associative, BinOp, BinOpAssign, LeftUnital, Magma, PowAssociative, RightUnital};
+
+use crate::{
+    permutation::Permutation,
+    storage::{ArrayStorageE, Iter, OwnedStorage, Size, StackStorage, Storage, VecStorageE},
+};
+
+/// Represents a transformation, that is, an arbitrary function from the
+/// numbers from 0 to its length minus 1 into themselves. This is stored as
+/// some storage of `usize` whose entries must all be less than the length of
+/// the transformation.
+///
+/// Contrary to a [`Permutation`], entries may be repeated.
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Transformation<S: Storage<Inner = usize>>(S);
+
+/// A statically-sized transformation, backed by an [`ArrayStorage`].
+pub type TransformationS<const N: usize> = Transformation<ArrayStorageE<usize, N>>;
+
+/// A dynamically-sized transformation, backed by an [`VecStorage`].
+pub type TransformationD = Transformation<VecStorageE<usize>>;
+
+impl<S: OwnedStorage<Inner = usize>> FromIterator<usize> for Transformation<S> {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<S: Storage<Inner = usize>> Index<usize> for Transformation<S> {
+    type Output = usize;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<S: Storage<Inner = usize>> From<Permutation<S>> for Transformation<S> {
+    fn from(p: Permutation<S>) -> Self {
+        Self(p.into_storage())
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> TryFrom<Transformation<S>> for Permutation<S> {
+    type Error = Transformation<S>;
+
+    /// Converts a transformation into a permutation, or gives it back if it
+    /// isn't bijective.
+    fn try_from(f: Transformation<S>) -> Result<Self, Self::Error> {
+        if f.is_bijective() {
+            // Safety: a bijective transformation is a permutation.
+            unsafe { Ok(Self::new_unchecked(f.0)) }
+        } else {
+            Err(f)
+        }
+    }
+}
+
+impl<S: Storage<Inner = usize>> Transformation<S> {
+    /// Returns the length of the transformation.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether the transformation is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the size of the transformation.
+    pub fn size(&self) -> Size<S> {
+        self.0.size()
+    }
+
+    /// Returns an iterator over the entries of the transformation.
+    pub fn iter(&self) -> iter::Copied<Iter<'_, S>> {
+        self.0.iter().copied()
+    }
+
+    /// Returns the underlying storage of the transformation.
+    pub fn into_storage(self) -> S {
+        self.0
+    }
+
+    /// Returns whether the transformation is the identity.
+    pub fn is_identity(&self) -> bool {
+        self.iter().enumerate().all(|(i, v)| i == v)
+    }
+
+    /// Returns whether the transformation is
+    /// [idempotent](https://en.wikipedia.org/wiki/Idempotence), meaning that
+    /// composing it with itself leaves it unchanged.
+    pub fn is_idempotent(&self) -> bool {
+        self.iter().all(|v| self[v] == v)
+    }
+
+    /// Returns whether the transformation is a bijection, which makes it a
+    /// permutation.
+    pub fn is_bijective(&self) -> bool {
+        self.rank() == self.len()
+    }
+
+    /// Returns the image of the transformation, in increasing order.
+    pub fn image(&self) -> Vec<usize> {
+        let mut in_image = vec![false; self.len()];
+        for v in self.iter() {
+            in_image[v] = true;
+        }
+
+        (0..self.len()).filter(|&v| in_image[v]).collect()
+    }
+
+    /// Returns the rank of the transformation, meaning the size of its image.
+    pub fn rank(&self) -> usize {
+        self.image().len()
+    }
+
+    /// Returns the [kernel](https://en.wikipedia.org/wiki/Kernel_(set_theory))
+    /// of the transformation, as a partition of its domain. Two points belong
+    /// to the same class whenever they have the same image. Every class is
+    /// sorted, and they're sorted by their smallest element.
+    pub fn kernel(&self) -> Vec<Vec<usize>> {
+        let mut class: Vec<Option<usize>> = vec![None; self.len()];
+        let mut kernel: Vec<Vec<usize>> = Vec::new();
+
+        for (i, v) in self.iter().enumerate() {
+            match class[v] {
+                Some(k) => kernel[k].push(i),
+                None => {
+                    class[v] = Some(kernel.len());
+                    kernel.push(vec![i]);
+                }
+            }
+        }
+
+        kernel
+    }
+
+    /// Returns the index `m` and period `r` of the transformation. These are
+    /// the least positive integers such that `fᵐ = fᵐ⁺ʳ`.
+    ///
+    /// The period is the least common multiple of the cycle lengths of the
+    /// functional graph, which may overflow for very large transformations.
+    pub fn index_period(&self) -> (usize, usize) {
+        /// The state of a point in the search.
+        #[derive(Clone, Copy, PartialEq, Eq)]
+        enum State {
+            Unvisited,
+            OnPath,
+            Done,
+        }
+
+        let n = self.len();
+        let mut state = vec![State::Unvisited; n];
+
+        // The distance from every point to a cycle, and the length of said
+        // cycle.
+        let mut depth = vec![0; n];
+        let mut cycle_len = vec![0; n];
+
+        for start in 0..n {
+            let mut path = Vec::new();
+            let mut i = start;
+            while state[i] == State::Unvisited {
+                state[i] = State::OnPath;
+                path.push(i);
+                i = self[i];
+            }
+
+            // We found a new cycle, starting at `i`.
+            if state[i] == State::OnPath {
+                let pos = path.iter().position(|&j| j == i).unwrap();
+                let len = path.len() - pos;
+                for j in path.drain(pos..) {
+                    state[j] = State::Done;
+                    cycle_len[j] = len;
+                }
+            }
+
+            for &j in path.iter().rev() {
+                let next = self[j];
+                state[j] = State::Done;
+                depth[j] = depth[next] + 1;
+                cycle_len[j] = cycle_len[next];
+            }
+        }
+
+        let index = depth.iter().copied().max().unwrap_or(0).max(1);
+        let period = cycle_len.iter().fold(1, |r, &c| lcm(r, c));
+        (index, period)
+    }
+}
+
+/// Returns the greatest common divisor of two numbers.
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+
+    a
+}
+
+/// Returns the least common multiple of two positive numbers.
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
+
+impl<S: OwnedStorage<Inner = usize>> Transformation<S> {
+    /// Initializes a new transformation. No invariants are checked.
+    ///
+    /// # Safety
+    /// All entries of the transformation must be less than its length.
+    pub unsafe fn new_unchecked(s: S) -> Self {
+        Self(s)
+    }
+
+    /// Initializes a new transformation. Will check that the entries are
+    /// valid. To forgo this check, use [`new_unchecked`].
+    pub fn new(s: S) -> Option<Self> {
+        if s.iter().all(|&v| v < s.len()) {
+            // Safety: we just checked the invariant.
+            unsafe { Some(Self::new_unchecked(s)) }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the identity transformation.
+    pub fn identity(size: Size<S>) -> Self {
+        (0..size.value()).collect()
+    }
+
+    /// Returns the transformation mapping every point to `v`.
+    ///
+    /// # Panics
+    /// This method will panic if `v` is out of bounds.
+    pub fn constant(size: Size<S>, v: usize) -> Self {
+        assert!(v < size.value(), "point out of bounds");
+        iter::repeat_n(v, size.value()).collect()
+    }
+
+    /// Composes two transformations together, so that the result maps `i` to
+    /// `f[g[i]]`.
+    ///
+    /// # Panics
+    /// This method will panic if `f` and `g` have different lengths.
+    pub fn compose<A: Storage<Inner = usize>, B: Storage<Inner = usize>>(
+        f: &Transformation<A>,
+        g: &Transformation<B>,
+    ) -> Self {
+        assert_eq!(f.len(), g.len(), "size mismatch");
+        g.iter().map(|v| f[v]).collect()
+    }
+
+    /// Composes the transformation with itself `e` times. If `e` is zero,
+    /// returns the identity.
+    pub fn pow(&self, mut e: usize) -> Self {
+        let mut res = Self::identity(self.size());
+        let mut x: Self = self.iter().collect();
+
+        while e != 0 {
+            if e % 2 == 1 {
+                res = Self::compose(&res, &x);
+            }
+
+            e /= 2;
+            if e != 0 {
+                x = Self::compose(&x, &x);
+            }
+        }
+
+        res
+    }
+
+    /// Returns the unique idempotent power `fᵏ` of the transformation, with
+    /// `k` the least positive exponent for which it's attained. Every element
+    /// of a finite semigroup has such a power.
+    pub fn idempotent_power(&self) -> (usize, Self) {
+        let (index, period) = self.index_period();
+        let k = index.div_ceil(period) * period;
+        (k, self.pow(k))
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> BinOp<algebra::Mul> for Transformation<S> {
+    type Output = Self;
+    type Err = ();
+
+    /// Composes two transformations, or returns an error if their lengths
+    /// differ.
+    fn bin_op(&self, rhs: &Self) -> Result<Self, ()> {
+        if self.len() == rhs.len() {
+            Ok(Self::compose(self, rhs))
+        } else {
+            Err(())
+        }
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> BinOpAssign<algebra::Mul> for Transformation<S> {
+    type Err = ();
+
+    fn bin_op_assign_lhs(&mut self, rhs: &Self) -> Result<(), ()> {
+        *self = BinOp::<algebra::Mul>::bin_op(self, rhs)?;
+        Ok(())
+    }
+
+    fn bin_op_assign_rhs(&self, rhs: &mut Self) -> Result<(), ()> {
+        if self.len() != rhs.len() {
+            return Err(());
+        }
+
+        for v in rhs.0.iter_mut() {
+            *v = self[*v];
+        }
+
+        Ok(())
+    }
+}
+
+/// Composing transformations of different lengths isn't a "normal"
+/// circumstance.
+impl<S: OwnedStorage<Inner = usize>> Magma<algebra::Mul> for Transformation<S> {}
+
+impl<S: OwnedStorage<Inner = usize>> PowAssociative<algebra::Mul> for Transformation<S> {}
+
+impl<S: OwnedStorage<Inner = usize>> Associative<algebra::Mul> for Transformation<S> {}
+
+impl<S: StackStorage<Inner = usize>> LeftUnital<algebra::Mul> for Transformation<S> {
+    fn left_id() -> Self {
+        Self::identity(Default::default())
+    }
+}
+
+impl<S: StackStorage<Inner = usize>> RightUnital<algebra::Mul> for Transformation<S> {
+    fn right_id() -> Self {
+        Self::identity(Default::default())
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Mul for Transformation<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<'a, S: OwnedStorage<Inner = usize>> Mul<&'a Self> for Transformation<S> {
+    type Output = Self;
+
+    fn mul(self, rhs: &'a Self) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Mul<Transformation<S>> for &Transformation<S> {
+    type Output = Transformation<S>;
+
+    fn mul(self, rhs: Transformation<S>) -> Self::Output {
+        self * &rhs
+    }
+}
+
+impl<S: OwnedStorage<Inner = usize>> Mul<&Transformation<S>> for &Transformation<S> {
+    type Output = Transformation<S>;
+
+    fn mul(self, rhs: &Transformation<S>) -> Self::Output {
+        Transformation::compose(self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::permutation::PermutationS;
+    use algebra::Unital;
+
+    #[test]
+    fn structure() {
+        let f = TransformationS::new([1, 1, 3, 0, 3].into()).unwrap();
+        assert_eq!(f.image(), vec![0, 1, 3]);
+        assert_eq!(f.rank(), 3);
+        assert_eq!(f.kernel(), vec![vec![0, 1], vec![2, 4], vec![3]]);
+        assert!(!f.is_idempotent());
+        assert!(TransformationS::new([0, 5].into()).is_none());
+    }
+
+    #[test]
+    fn monoid() {
+        let f = TransformationS::new([1, 1, 3, 0].into()).unwrap();
+        let g = TransformationS::new([2, 0, 0, 3].into()).unwrap();
+        let h = TransformationS::new([3, 2, 1, 1].into()).unwrap();
+
+        assert_eq!(Magma::<algebra::Mul>::op(&f, &g), f * g);
+        assert_eq!((f * g).iter().collect::<Vec<_>>(), vec![3, 1, 1, 0]);
+        assert!(Associative::<algebra::Mul>::test_assoc(&f, &g, &h));
+        assert!(LeftUnital::<algebra::Mul>::test_left_id(&f));
+        assert!(RightUnital::<algebra::Mul>::test_right_id(&g));
+        assert!(<TransformationS<4> as Unital<algebra::Mul>>::id().is_identity());
+
+        let mut k = f;
+        Magma::<algebra::Mul>::op_assign_rhs(&g, &mut k);
+        assert_eq!(k, g * f);
+    }
+
+    #[test]
+    fn idempotent_power() {
+        // A 3-cycle with a tail of length 2 attached.
+        let f = TransformationS::new([1, 2, 3, 4, 2].into()).unwrap();
+        assert_eq!(f.index_period(), (2, 3));
+
+        let (k, e) = f.idempotent_power();
+        assert_eq!(k, 3);
+        assert!(e.is_idempotent());
+        assert!(!f.pow(2).is_idempotent());
+        assert_eq!(f.pow(k + 3), e);
+
+        let c = TransformationS::<4>::constant(Default::default(), 2);
+        assert_eq!(c.idempotent_power(), (1, c));
+    }
+
+    #[test]
+    fn permutation() {
+        let p = PermutationS::new([2, 0, 3, 1].into()).unwrap();
+        let f = Transformation::from(p);
+        assert!(f.is_bijective());
+        assert_eq!(Permutation::try_from(f), Ok(p));
+
+        let g = TransformationS::new([2, 0, 2, 1].into()).unwrap();
+        assert_eq!(Permutation::try_from(g), Err(g));
+        assert_eq!(f.idempotent_power().1, Transformation::identity(f.size()));
+        assert_eq!(f.index_period(), (1, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn compose_size_mismatch() {
+        let f = TransformationD::new(vec![0, 0, 2].into_iter().collect()).unwrap();
+        let g = TransformationD::new(vec![1, 0].into_iter().collect()).unwrap();
+        TransformationD::compose(&f, &g);
+    }
+}